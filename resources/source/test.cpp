@@ -0,0 +1,9 @@
+#include "test.h"
+
+void Example()
+{
+	//// [snippet: inventory_usage]
+	FInventory Inventory;
+	Inventory.Validate();
+	//// [/snippet]
+}
@@ -18,8 +18,13 @@ void* Main(const Foo& Arg);
 UENUM(BlueprintType, Meta = (Foo = Bar))
 enum class Something : uint8
 {
-	A,
-	B
+	/// First variant.
+	A = 4 UMETA(DisplayName = "Alpha"),
+	/// Second variant.
+	///
+	/// With longer description.
+	B UMETA(Hidden),
+	C
 };
 
 /// Description of struct
@@ -47,10 +52,23 @@ private:
 	/// What is this property
 	///
 	/// What impact does it have
+	//// [group: State]
+	//// [alias: Value]
 	UPROPERTY()
 	int A[] = {0};
+
+	/// Implementation detail that stays out of documentation.
+	//// [hidden]
+	UPROPERTY()
+	int Cache = 0;
 };
 
+/// Called when something got hit.
+DECLARE_DYNAMIC_MULTICAST_DELEGATE_TwoParams(FOnHit, AActor*, Target, float, Damage);
+
+/// Native delegate returning value.
+DECLARE_DELEGATE_RetVal_OneParam(bool, FOnValidate, const FString&);
+
 /// Description of class
 ///
 /// More information and examples.
@@ -64,6 +82,10 @@ public:
 	///
 	/// What it does
 	Bar();
+
+	/// Broadcasted on hit.
+	UPROPERTY(BlueprintAssignable)
+	FOnHit OnHit;
 };
 
 /// What is this function
@@ -147,24 +169,172 @@ struct Who
 	INJECT
 };
 
+/// Handle generated with macro.
+//// [proxy: handles]
+//// struct FHandle
+//// {
+//// 	/// Handle value.
+//// 	int32 Value = 0;
+//// };
+//// [/proxy]
+#define GENERATE_HANDLE \
+	struct FHandle      \
+	{                   \
+		int32 Value = 0; \
+	};
+
+/// Returns $Name$ of the library.
+//// [proxy: PROPERTY_GETTER(Type, Name)]
+//// static $Type$ Get$Name$();
+//// [/proxy]
+#define PROPERTY_GETTER(Type, Name) \
+	static Type Get##Name()         \
+	{                               \
+		return Type();              \
+	}
+
 /// Test blueprint library.
 UCLASS()
 class UTestLibrary : public UBlueprintFunctionLibrary
 {
 	GENERATED_BODY()
 
+	//// [inject: injectable, handles]
+	INJECT
+	GENERATE_HANDLE
+	PROPERTY_GETTER(float, Delay)
+
 public:
 #pragma region
 	/// Test blueprint function.
 	UFUNCTION(BlueprintCallable, Category = "TestLibrary")
 	static void SetXX(
 		const UObject* WCO,
-		FTimerHandle& InOutHandle,
+		/// Timer handle gets updated in place.
+		UPARAM(ref) FTimerHandle& InOutHandle,
 		/// Function type signature support.
 		TFunction<void(int, float)>&& Callback,
 		float Internal,
-		bool InbLoop,
+		UPARAM(DisplayName = "Loop") bool InbLoop,
 		float InFirstDelay = 1.f,
 	);
+
+	/// Finds index of value in array.
+	///
+	/// @param Values Values to search.
+	/// @param Value Value to find.
+	/// @return Index of value, or `INDEX_NONE` if not found.
+	/// @note Search is linear.
+	/// @see UTestLibrary::SetXX
+	UFUNCTION(BlueprintPure, Category = "TestLibrary")
+	static int32 FindIndex(const TArray<int32>& Values, int32 Value);
 #pragma endregion
-};
\ No newline at end of file
+};
+
+UINTERFACE(MinimalAPI, Blueprintable)
+class UTestInterface : public UInterface
+{
+	GENERATED_BODY()
+};
+
+/// Description of interface.
+///
+/// [`interface: ITestInterface`]()
+class FOO ITestInterface
+{
+	GENERATED_BODY()
+
+public:
+	/// Interface method.
+	UFUNCTION(BlueprintNativeEvent, BlueprintCallable)
+	void Interact(AActor* Instigator);
+};
+
+namespace MyGame::AI
+{
+	/// Utility function living in nested namespace.
+	///
+	/// Not to be confused with [`function: MyGame::AI::Detail::Utils`]().
+	void Utils();
+
+	namespace Detail
+	{
+		/// Utility function living in deeper namespace.
+		void Utils();
+	}
+}
+
+/// Inventory with nested types.
+///
+/// [`struct: Self::FSlot`]()
+struct FInventory
+{
+	/// Single inventory slot.
+	///
+	/// [`enum: Self::EKind`]()
+	struct FSlot
+	{
+		/// Kind of slot content.
+		enum class EKind : uint8
+		{
+			Empty,
+			Item
+		};
+
+		/// Slot content kind.
+		EKind Kind;
+	};
+
+#if WITH_EDITOR
+	/// Validates inventory content in editor.
+	void Validate() const;
+#endif
+};
+
+/// Old utility function.
+UE_DEPRECATED(5.1, "Use MyGame::AI::Utils instead.")
+void OldUtils();
+
+/// Identifier of inventory item.
+///
+/// See [`alias: FItemId`]().
+using FItemId = TTuple<int32, FName>;
+
+namespace MyGame
+{
+	/// Name of socket items get attached to.
+	extern MYGAME_API const FName DefaultSocket;
+
+	/// Maximal number of players in session.
+	static constexpr int32 MaxPlayers = 4;
+}
+
+/// Describes how items of given type get stored.
+///
+/// See [`struct: TItemTraits<FItemId>`]().
+//// [group: Inventory]
+//// [alias: TStorageTraits]
+template <
+	/// Stored item type.
+	typename T,
+	int32 Capacity = 8 ///< Initial capacity of storage.
+>
+struct TItemTraits
+{
+};
+
+/// Storage traits of item identifiers.
+//// [group: Inventory]
+template <>
+struct TItemTraits<FItemId>
+{
+};
+
+/// Inventory slot replaced by item storage traits.
+struct MYGAME_API_DEPRECATED(5.1, "Use TItemTraits instead.") FInventorySlot
+{
+	/// Item stored in slot.
+	FORCEINLINE_DEBUGGABLE static FItemId Item;
+};
+
+UE_INLINE_GENERATED_CPP_BY_NAME(test)
@@ -1 +1,2 @@
+pub mod preprocessor;
 pub mod unreal_cpp_header;
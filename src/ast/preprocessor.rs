@@ -0,0 +1,679 @@
+use std::collections::HashMap;
+
+/// Preprocessor condition each line of header content sits under.
+#[derive(Debug, Default, Clone)]
+pub struct Conditions {
+    lines: Vec<Option<String>>,
+}
+
+impl Conditions {
+    pub fn new(content: &str) -> Self {
+        let lines = content.lines().collect::<Vec<_>>();
+        let mut stack = Vec::<Frame>::new();
+        let mut result = Vec::with_capacity(lines.len());
+        let mut index = 0;
+        while index < lines.len() {
+            let condition = condition(&stack);
+            let mut directive = lines[index].trim().to_owned();
+            // Directives can continue in following lines.
+            while directive.ends_with('\\') && index + 1 < lines.len() {
+                directive.pop();
+                result.push(condition.to_owned());
+                index += 1;
+                directive.push(' ');
+                directive.push_str(lines[index].trim());
+            }
+            result.push(condition);
+            index += 1;
+            let directive = match directive.strip_prefix('#') {
+                Some(directive) => strip_comments(directive),
+                None => continue,
+            };
+            let (name, expression) = match directive.find(|c: char| !c.is_alphanumeric()) {
+                Some(position) => (&directive[..position], directive[position..].trim()),
+                None => (directive.as_str(), ""),
+            };
+            match name {
+                "if" => stack.push(Frame::new(Part::Positive(expression.to_owned()))),
+                "ifdef" => stack.push(Frame::new(Part::Positive(format!(
+                    "defined({})",
+                    expression
+                )))),
+                "ifndef" => {
+                    let guard = lines[index..]
+                        .iter()
+                        .map(|line| line.trim())
+                        .find(|line| !line.is_empty())
+                        .and_then(|line| line.strip_prefix('#'))
+                        .and_then(|line| line.trim().strip_prefix("define"))
+                        .map(|line| line.trim() == expression)
+                        .unwrap_or_default();
+                    let mut frame = Frame::new(Part::Negative(format!("defined({})", expression)));
+                    frame.is_guard = guard;
+                    stack.push(frame);
+                }
+                "elif" => {
+                    if let Some(frame) = stack.last_mut() {
+                        frame.next_branch(Some(expression.to_owned()));
+                    }
+                }
+                "else" => {
+                    if let Some(frame) = stack.last_mut() {
+                        frame.next_branch(None);
+                    }
+                }
+                "endif" => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+        Self { lines: result }
+    }
+
+    /// Returns condition of given line, numbered from 1.
+    pub fn get(&self, line: usize) -> Option<&str> {
+        self.lines
+            .get(line.saturating_sub(1))
+            .and_then(|condition| condition.as_deref())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Part {
+    Positive(String),
+    Negative(String),
+}
+
+#[derive(Debug)]
+struct Frame {
+    parts: Vec<Part>,
+    is_guard: bool,
+}
+
+impl Frame {
+    fn new(part: Part) -> Self {
+        Self {
+            parts: vec![part],
+            is_guard: false,
+        }
+    }
+
+    /// Negates current branch condition and starts new branch, which is `#else` if there is
+    /// no expression provided.
+    fn next_branch(&mut self, expression: Option<String>) {
+        self.is_guard = false;
+        if let Some(part) = self.parts.pop() {
+            self.parts.push(match part {
+                Part::Positive(expression) => Part::Negative(expression),
+                Part::Negative(expression) => Part::Positive(expression),
+            });
+        }
+        if let Some(expression) = expression {
+            self.parts.push(Part::Positive(expression));
+        }
+    }
+}
+
+fn condition(stack: &[Frame]) -> Option<String> {
+    let parts = stack
+        .iter()
+        .filter(|frame| !frame.is_guard)
+        .flat_map(|frame| frame.parts.iter())
+        .collect::<Vec<_>>();
+    match parts.as_slice() {
+        [] => None,
+        [Part::Positive(expression)] => Some(expression.to_owned()),
+        parts => Some(
+            parts
+                .iter()
+                .map(|part| match part {
+                    Part::Positive(expression) => wrap(expression),
+                    Part::Negative(expression) => match expression.strip_prefix('!') {
+                        Some(inner) if wrap(inner) == inner => inner.to_owned(),
+                        _ => format!("!{}", wrap(expression)),
+                    },
+                })
+                .collect::<Vec<_>>()
+                .join(" && "),
+        ),
+    }
+}
+
+fn wrap(expression: &str) -> String {
+    let is_atom = expression.chars().all(|c| c.is_alphanumeric() || c == '_')
+        || (expression.starts_with("defined(")
+            && expression.ends_with(')')
+            && expression.matches('(').count() == 1);
+    if is_atom {
+        expression.to_owned()
+    } else {
+        format!("({})", expression)
+    }
+}
+
+fn strip_comments(content: &str) -> String {
+    let content = match content.find("//") {
+        Some(position) => &content[..position],
+        None => content,
+    };
+    let mut result = String::new();
+    let mut rest = content;
+    while let Some(start) = rest.find("/*") {
+        result.push_str(&rest[..start]);
+        rest = match rest[start..].find("*/") {
+            Some(end) => &rest[(start + end + 2)..],
+            None => "",
+        };
+    }
+    result.push_str(rest);
+    result.trim().to_owned()
+}
+
+/// Evaluates preprocessor condition expression.
+///
+/// If `complete` is set, undefined macros evaluate to zero like in actual preprocessor,
+/// otherwise they make result unknown unless short-circuit logic decides it. Returns `None`
+/// if result is unknown or expression is not supported.
+pub fn evaluate(expression: &str, defines: &HashMap<String, i64>, complete: bool) -> Option<i64> {
+    let tokens = tokenize(expression)?;
+    let mut evaluator = Evaluator {
+        tokens: &tokens,
+        position: 0,
+        defines,
+        complete,
+    };
+    let result = evaluator.or()?;
+    if evaluator.position == tokens.len() {
+        result
+    } else {
+        None
+    }
+}
+
+/// Tells if item under given condition gets compiled only in editor builds.
+pub fn is_editor_only(condition: &str) -> bool {
+    let defines = HashMap::from([
+        ("WITH_EDITOR".to_owned(), 0),
+        ("WITH_EDITORONLY_DATA".to_owned(), 0),
+    ]);
+    evaluate(condition, &defines, false) == Some(0)
+}
+
+/// Tells if item under given condition gets compiled out of shipping builds.
+pub fn is_non_shipping(condition: &str) -> bool {
+    let defines = HashMap::from([("UE_BUILD_SHIPPING".to_owned(), 1)]);
+    evaluate(condition, &defines, false) == Some(0)
+}
+
+/// User defined macros expanded in header content before parsing.
+#[derive(Debug, Default, Clone)]
+pub struct Macros {
+    definitions: HashMap<String, Macro>,
+}
+
+#[derive(Debug, Clone)]
+struct Macro {
+    /// Parameters of function-like macro, where `...` stands for variadic arguments.
+    parameters: Option<Vec<String>>,
+    body: String,
+}
+
+impl Macros {
+    /// Creates macros out of definitions keyed by either macro name or function-like macro
+    /// signature, like `NAME(A, B)` or `NAME(A, ...)`. Empty body strips macro.
+    pub fn new(definitions: &HashMap<String, String>) -> Self {
+        let definitions = definitions
+            .iter()
+            .map(|(signature, body)| {
+                let signature = signature.trim();
+                let (name, parameters) = match signature.find('(') {
+                    Some(position) => {
+                        let parameters = signature[(position + 1)..]
+                            .trim_end()
+                            .trim_end_matches(')')
+                            .split(',')
+                            .map(|parameter| parameter.trim().to_owned())
+                            .filter(|parameter| !parameter.is_empty())
+                            .collect();
+                        (signature[..position].trim(), Some(parameters))
+                    }
+                    None => (signature, None),
+                };
+                let body = body.trim().replace(['\r', '\n'], " ");
+                (name.to_owned(), Macro { parameters, body })
+            })
+            .collect();
+        Self { definitions }
+    }
+
+    /// Expands macros in code, leaving comments, string literals, preprocessor directives and
+    /// snippet blocks intact. Line breaks of macro invocations are kept after their expansion
+    /// so that lines of the remaining content do not change, but expansion itself is put on
+    /// the first line of invocation and columns following it on that line get shifted.
+    pub fn expand(&self, content: &str) -> String {
+        if self.definitions.is_empty() {
+            content.to_owned()
+        } else {
+            self.expand_code(content, &[], true)
+        }
+    }
+
+    fn expand_code(&self, content: &str, disabled: &[&str], mut line_start: bool) -> String {
+        let mut result = String::with_capacity(content.len());
+        let mut rest = content;
+        let mut snippet = false;
+        while let Some(c) = rest.chars().next() {
+            let length = if line_start && c == '#' {
+                directive_length(rest)
+            } else if let Some(length) = literal_length(rest) {
+                if let Some(comment) = rest[..length].strip_prefix("////") {
+                    let comment = comment.trim_start();
+                    if comment.starts_with("[snippet") {
+                        snippet = true;
+                    } else if comment.starts_with("[/snippet") {
+                        snippet = false;
+                    }
+                }
+                length
+            } else if c.is_ascii_digit() {
+                rest.find(|c: char| !c.is_alphanumeric() && !matches!(c, '_' | '.' | '\''))
+                    .unwrap_or(rest.len())
+            } else if c.is_alphabetic() || c == '_' {
+                let length = identifier_length(rest);
+                let name = &rest[..length];
+                if let Some(raw) = raw_string_length(name, &rest[length..]) {
+                    length + raw
+                } else if snippet || disabled.contains(&name) {
+                    length
+                } else if let Some((expansion, consumed)) =
+                    self.invoke(name, &rest[length..], disabled)
+                {
+                    result.push_str(&expansion);
+                    rest = &rest[(length + consumed)..];
+                    line_start = expansion.ends_with('\n');
+                    continue;
+                } else {
+                    length
+                }
+            } else {
+                c.len_utf8()
+            };
+            if c == '\n' {
+                line_start = true;
+            } else if !c.is_whitespace() {
+                line_start = false;
+            }
+            result.push_str(&rest[..length]);
+            rest = &rest[length..];
+        }
+        result
+    }
+
+    /// Expands macro of given name, if there is one, using content following its name for
+    /// arguments. Returns expansion and length of content consumed for arguments.
+    fn invoke(&self, name: &str, content: &str, disabled: &[&str]) -> Option<(String, usize)> {
+        let definition = self.definitions.get(name)?;
+        let mut inner = disabled.to_vec();
+        inner.push(name);
+        let parameters = match &definition.parameters {
+            Some(parameters) => parameters,
+            None => return Some((self.expand_code(&definition.body, &inner, false), 0)),
+        };
+        let start = content.len() - content.trim_start().len();
+        if !content[start..].starts_with('(') {
+            return None;
+        }
+        let (arguments, length) = split_arguments(&content[start..])?;
+        let consumed = start + length;
+        let arguments = arguments
+            .iter()
+            .map(|argument| {
+                self.expand_code(argument, disabled, false)
+                    .replace(['\r', '\n'], " ")
+            })
+            .collect::<Vec<_>>();
+        let argument = |parameter: &str| {
+            if parameter == "__VA_ARGS__" {
+                let index = parameters.iter().position(|item| item == "...")?;
+                return Some(arguments.get(index..).unwrap_or_default().join(", "));
+            }
+            let index = parameters.iter().position(|item| item == parameter)?;
+            Some(arguments.get(index).cloned().unwrap_or_default())
+        };
+        let body = substitute(&definition.body, argument);
+        let mut result = self.expand_code(&body, &inner, false);
+        result.push_str(&"\n".repeat(content[..consumed].matches('\n').count()));
+        Some((result, consumed))
+    }
+}
+
+/// Replaces parameters in macro body with arguments, applying `#` and `##` operators.
+fn substitute(body: &str, argument: impl Fn(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(body.len());
+    let mut rest = body;
+    while let Some(c) = rest.chars().next() {
+        if let Some(length) = literal_length(rest) {
+            result.push_str(&rest[..length]);
+            rest = &rest[length..];
+        } else if let Some(pasted) = rest.strip_prefix("##") {
+            result.truncate(result.trim_end().len());
+            rest = pasted.trim_start();
+        } else if c == '#' {
+            let name = rest[1..].trim_start();
+            let length = identifier_length(name);
+            match argument(&name[..length]) {
+                Some(value) if length > 0 => {
+                    result.push_str(&format!("{:?}", value.trim()));
+                    rest = &name[length..];
+                }
+                _ => {
+                    result.push(c);
+                    rest = &rest[1..];
+                }
+            }
+        } else if c.is_alphanumeric() || c == '_' {
+            let length = identifier_length(rest);
+            let name = &rest[..length];
+            match argument(name) {
+                Some(value) => result.push_str(value.trim()),
+                None => result.push_str(name),
+            }
+            rest = &rest[length..];
+        } else {
+            result.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    result
+}
+
+/// Splits arguments of macro invocation that starts with `(`, returning them along with
+/// length of the whole invocation including closing `)`.
+fn split_arguments(content: &str) -> Option<(Vec<&str>, usize)> {
+    let mut result = vec![];
+    let mut depth = 0usize;
+    let mut start = 1;
+    let mut position = 0;
+    while position < content.len() {
+        let rest = &content[position..];
+        if let Some(length) = literal_length(rest) {
+            position += length;
+            continue;
+        }
+        let c = rest.chars().next()?;
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    result.push(content[start..position].trim());
+                    return Some((result, position + 1));
+                }
+            }
+            ',' if depth == 1 => {
+                result.push(content[start..position].trim());
+                start = position + 1;
+            }
+            _ => {}
+        }
+        position += c.len_utf8();
+    }
+    None
+}
+
+/// Returns length of preprocessor directive, including its continuation lines.
+fn directive_length(content: &str) -> usize {
+    let mut position = 0;
+    loop {
+        match content[position..].find('\n') {
+            Some(end)
+                if content[position..(position + end)]
+                    .trim_end()
+                    .ends_with('\\') =>
+            {
+                position += end + 1
+            }
+            Some(end) => return position + end,
+            None => return content.len(),
+        }
+    }
+}
+
+/// Returns length of comment or string/character literal content starts with.
+fn literal_length(content: &str) -> Option<usize> {
+    if content.starts_with("//") {
+        Some(content.find('\n').unwrap_or(content.len()))
+    } else if let Some(rest) = content.strip_prefix("/*") {
+        Some(rest.find("*/").map(|end| end + 4).unwrap_or(content.len()))
+    } else if let Some(quote @ ('"' | '\'')) = content.chars().next() {
+        let mut escaped = false;
+        for (index, c) in content.char_indices().skip(1) {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '\n' => return Some(index),
+                _ if c == quote => return Some(index + 1),
+                _ => {}
+            }
+        }
+        Some(content.len())
+    } else {
+        None
+    }
+}
+
+/// Returns length of raw string literal if given prefix starts one.
+fn raw_string_length(prefix: &str, content: &str) -> Option<usize> {
+    if !matches!(prefix, "R" | "LR" | "uR" | "UR" | "u8R") {
+        return None;
+    }
+    let rest = content.strip_prefix('"')?;
+    let delimiter = &rest[..rest.find('(')?];
+    let terminator = format!("){}\"", delimiter);
+    Some(
+        rest.find(&terminator)
+            .map(|end| end + terminator.len() + 1)
+            .unwrap_or(content.len()),
+    )
+}
+
+fn identifier_length(content: &str) -> usize {
+    content
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(content.len())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Identifier(String),
+    Number(i64),
+    Operator(&'static str),
+}
+
+fn tokenize(content: &str) -> Option<Vec<Token>> {
+    const OPERATORS: [&str; 17] = [
+        "&&", "||", "==", "!=", "<=", ">=", "<", ">", "!", "+", "-", "*", "/", "%", "(", ")", ",",
+    ];
+    let mut result = vec![];
+    let mut rest = content.trim_start();
+    while !rest.is_empty() {
+        let c = rest.chars().next().unwrap();
+        if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            let literal = rest[..end].trim_end_matches(['u', 'U', 'l', 'L']);
+            let value = if let Some(hex) = literal
+                .strip_prefix("0x")
+                .or_else(|| literal.strip_prefix("0X"))
+            {
+                i64::from_str_radix(hex, 16).ok()?
+            } else {
+                literal.parse().ok()?
+            };
+            result.push(Token::Number(value));
+            rest = &rest[end..];
+        } else if c.is_alphabetic() || c == '_' {
+            let end = rest
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            result.push(Token::Identifier(rest[..end].to_owned()));
+            rest = &rest[end..];
+        } else {
+            let operator = OPERATORS
+                .iter()
+                .find(|operator| rest.starts_with(*operator))?;
+            result.push(Token::Operator(operator));
+            rest = &rest[operator.len()..];
+        }
+        rest = rest.trim_start();
+    }
+    Some(result)
+}
+
+/// Recursive descent evaluator, where `None` inside `Some` means unknown value.
+struct Evaluator<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    defines: &'a HashMap<String, i64>,
+    complete: bool,
+}
+
+impl<'a> Evaluator<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let result = self.tokens.get(self.position);
+        self.position += 1;
+        result
+    }
+
+    fn accept(&mut self, operators: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Operator(operator)) if operators.contains(operator) => {
+                self.position += 1;
+                Some(operator)
+            }
+            _ => None,
+        }
+    }
+
+    fn or(&mut self) -> Option<Option<i64>> {
+        let mut result = self.and()?;
+        while self.accept(&["||"]).is_some() {
+            let other = self.and()?;
+            result = match (result, other) {
+                (Some(a), _) if a != 0 => Some(1),
+                (_, Some(b)) if b != 0 => Some(1),
+                (Some(_), Some(_)) => Some(0),
+                _ => None,
+            };
+        }
+        Some(result)
+    }
+
+    fn and(&mut self) -> Option<Option<i64>> {
+        let mut result = self.binary(0)?;
+        while self.accept(&["&&"]).is_some() {
+            let other = self.binary(0)?;
+            result = match (result, other) {
+                (Some(0), _) | (_, Some(0)) => Some(0),
+                (Some(_), Some(_)) => Some(1),
+                _ => None,
+            };
+        }
+        Some(result)
+    }
+
+    fn binary(&mut self, level: usize) -> Option<Option<i64>> {
+        const LEVELS: [&[&str]; 4] = [
+            &["==", "!="],
+            &["<=", ">=", "<", ">"],
+            &["+", "-"],
+            &["*", "/", "%"],
+        ];
+        if level >= LEVELS.len() {
+            return self.unary();
+        }
+        let mut result = self.binary(level + 1)?;
+        while let Some(operator) = self.accept(LEVELS[level]) {
+            let other = self.binary(level + 1)?;
+            result = match (result, other) {
+                (Some(a), Some(b)) => match operator {
+                    "==" => Some((a == b) as i64),
+                    "!=" => Some((a != b) as i64),
+                    "<=" => Some((a <= b) as i64),
+                    ">=" => Some((a >= b) as i64),
+                    "<" => Some((a < b) as i64),
+                    ">" => Some((a > b) as i64),
+                    "+" => a.checked_add(b),
+                    "-" => a.checked_sub(b),
+                    "*" => a.checked_mul(b),
+                    "/" => a.checked_div(b),
+                    "%" => a.checked_rem(b),
+                    _ => None,
+                },
+                _ => None,
+            };
+        }
+        Some(result)
+    }
+
+    fn unary(&mut self) -> Option<Option<i64>> {
+        match self.accept(&["!", "-", "+"]) {
+            Some("!") => Some(self.unary()?.map(|value| (value == 0) as i64)),
+            Some("-") => Some(self.unary()?.and_then(|value| value.checked_neg())),
+            Some(_) => self.unary(),
+            None => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Option<Option<i64>> {
+        match self.next()? {
+            Token::Number(value) => Some(Some(*value)),
+            Token::Operator("(") => {
+                let result = self.or()?;
+                self.accept(&[")"])?;
+                Some(result)
+            }
+            Token::Identifier(name) if name == "defined" => {
+                let parens = self.accept(&["("]).is_some();
+                let name = match self.next()? {
+                    Token::Identifier(name) => name,
+                    _ => return None,
+                };
+                if parens {
+                    self.accept(&[")"])?;
+                }
+                Some(match self.defines.contains_key(name) {
+                    true => Some(1),
+                    false if self.complete => Some(0),
+                    false => None,
+                })
+            }
+            Token::Identifier(name) => {
+                if self.accept(&["("]).is_some() {
+                    // Function-like macros can not be evaluated.
+                    let mut depth = 1;
+                    while depth > 0 {
+                        match self.next()? {
+                            Token::Operator("(") => depth += 1,
+                            Token::Operator(")") => depth -= 1,
+                            _ => {}
+                        }
+                    }
+                    return Some(None);
+                }
+                Some(match self.defines.get(name) {
+                    Some(value) => Some(*value),
+                    None if self.complete => Some(0),
+                    None => None,
+                })
+            }
+            _ => None,
+        }
+    }
+}
@@ -1,538 +1,3501 @@
-use crate::{config::Settings, document::*};
-use pest::{error::Error, iterators::Pair, Parser};
-use std::collections::HashSet;
+use crate::{
+    ast::preprocessor::{self, Conditions, Macros},
+    config::Settings,
+    document::*,
+};
+use pest::{
+    error::{Error, ErrorVariant, LineColLocation},
+    iterators::Pair,
+    Parser,
+};
+use std::{collections::HashSet, ops::Range};
 
 #[derive(Parser)]
 #[grammar = "ast/unreal_cpp_header.pest"]
 pub struct UnrealCppHeaderParser;
 
-#[allow(clippy::result_large_err)]
+/// Declaration skipped because it could not be parsed.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn from_error(error: Error<Rule>, declaration: &str) -> Self {
+        let (line, column) = match error.line_col {
+            LineColLocation::Pos(location) => location,
+            LineColLocation::Span(location, _) => location,
+        };
+        let declaration = declaration.trim().lines().next().unwrap_or_default();
+        Self {
+            line,
+            column,
+            message: format!(
+                "Skipped unsupported declaration `{}`: {}",
+                declaration,
+                error.variant.message()
+            ),
+        }
+    }
+}
+
+/// Header file content being parsed.
+#[derive(Default)]
+struct Source {
+    /// Path relative to input directory.
+    file: String,
+    conditions: Conditions,
+}
+
+impl Source {
+    /// Returns preprocessor condition of the line element declaration (not its doc comments)
+    /// starts in.
+    fn condition(&self, pair: &Pair<Rule>) -> Option<String> {
+        self.conditions
+            .get(declaration_start_line(pair))
+            .map(|condition| condition.to_owned())
+    }
+
+    fn location(&self, pair: &Pair<Rule>) -> SourceLocation {
+        SourceLocation {
+            file: self.file.to_owned(),
+            start_line: declaration_start_line(pair),
+            end_line: pair.as_span().end_pos().line_col().0,
+        }
+    }
+}
+
+/// Returns line element declaration starts in, skipping its doc comments.
+fn declaration_start_line(pair: &Pair<Rule>) -> usize {
+    pair.clone()
+        .into_inner()
+        .find(|pair| pair.as_rule() != Rule::doc_comment_lines)
+        .unwrap_or_else(|| pair.clone())
+        .as_span()
+        .start_pos()
+        .line_col()
+        .0
+}
+
+/// Parses header content into document, skipping declarations that could not be parsed.
+/// File path relative to input directory gets stored in source locations of items, which
+/// point at original content except for positions within lines of expanded macros (see
+/// [`Macros::expand`]).
 pub fn parse_unreal_cpp_header(
+    file: &str,
     content: &str,
     document: &mut Document,
     settings: &Settings,
-) -> Result<(), Error<Rule>> {
-    let pair = UnrealCppHeaderParser::parse(Rule::file, content)?
-        .next()
-        .unwrap();
-    if pair.as_rule() == Rule::file {
-        parse_file(pair, document, settings);
+) -> Vec<Diagnostic> {
+    let content = if settings.block_doc_comments {
+        content.to_owned()
+    } else {
+        disable_doc_comment_blocks(content)
+    };
+    let content = Macros::new(&settings.macros).expand(&content);
+    let source = Source {
+        file: file.to_owned(),
+        conditions: Conditions::new(&content),
+    };
+    let mut diagnostics = vec![];
+    match UnrealCppHeaderParser::parse(Rule::file, &content) {
+        Ok(mut pairs) => {
+            let pair = pairs.next().unwrap();
+            parse_file(pair, document, settings, &source, &mut diagnostics);
+        }
+        Err(_) => parse_scope_recovering(
+            &content,
+            0..content.len(),
+            None,
+            document,
+            settings,
+            &source,
+            &mut diagnostics,
+        ),
     }
-    Ok(())
+    diagnostics
 }
 
-fn parse_unreal_cpp_element(
+/// Scans source file content only for snippets and proxies, ignoring declarations.
+pub fn parse_unreal_cpp_source(
+    file: &str,
     content: &str,
     document: &mut Document,
     settings: &Settings,
-) -> Element {
-    let pair = UnrealCppHeaderParser::parse(Rule::element, content)
-        .unwrap_or_else(|error| {
-            panic!(
-                "Could not parse Unreal C++ element content!\nError:\n{}",
-                error
-            )
-        })
-        .next()
-        .unwrap();
-    match pair.as_rule() {
-        Rule::element => parse_element(pair, Visibility::Public, settings, document),
-        _ => unreachable!(),
+) -> Vec<Diagnostic> {
+    let source = Source {
+        file: file.to_owned(),
+        conditions: Conditions::new(content),
+    };
+    let mut diagnostics = vec![];
+    match UnrealCppHeaderParser::parse(Rule::source_file, content) {
+        Ok(mut pairs) => {
+            for pair in pairs.next().unwrap().into_inner() {
+                match pair.as_rule() {
+                    Rule::proxy => {
+                        parse_proxy(pair, None, settings, &source, document, &mut diagnostics)
+                    }
+                    Rule::snippet => parse_snippet(pair, document),
+                    _ => {}
+                }
+            }
+        }
+        Err(error) => diagnostics.push(Diagnostic::from_error(error, content)),
     }
+    diagnostics
 }
 
-fn parse_file(pair: Pair<Rule>, document: &mut Document, settings: &Settings) {
-    for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::proxy => parse_proxy(pair, settings, document),
-            Rule::snippet => parse_snippet(pair, document),
-            Rule::element => match parse_element(pair, Visibility::Public, settings, document) {
-                Element::Enum(element) => {
-                    if element.can_export(settings) {
-                        if document.enums.iter().any(|item| item.name == element.name) {
-                            println!("Overwriting existing enum: {}", element.name);
+/// Turns `/** */` doc comment blocks into regular comment blocks, keeping content length
+/// intact.
+fn disable_doc_comment_blocks(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        result.push(c);
+        match c {
+            '"' | '\'' => {
+                while let Some(n) = chars.next() {
+                    result.push(n);
+                    if n == '\\' {
+                        if let Some(n) = chars.next() {
+                            result.push(n);
                         }
-                        document.enums.push(element)
+                    } else if n == c || n == '\n' {
+                        break;
                     }
                 }
-                Element::StructClass(element) => match element.mode {
-                    StructClassMode::Struct => {
-                        if element.can_export(settings) {
-                            if document
-                                .structs
-                                .iter()
-                                .any(|item| item.name == element.name)
-                            {
-                                println!("Overwriting existing struct: {}", element.name);
-                            }
-                            document.structs.push(element)
-                        }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for n in chars.by_ref() {
+                    result.push(n);
+                    if n == '\n' {
+                        break;
                     }
-                    StructClassMode::Class => {
-                        if element.can_export(settings) {
-                            if document
-                                .classes
-                                .iter()
-                                .any(|item| item.name == element.name)
-                            {
-                                println!("Overwriting existing class: {}", element.name);
-                            }
-                            document.classes.push(element)
-                        }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                result.push(chars.next().unwrap());
+                let mut last = ' ';
+                if chars.peek() == Some(&'*') {
+                    chars.next();
+                    if matches!(chars.peek(), Some('*' | '/')) {
+                        result.push('*');
+                        last = '*';
+                    } else {
+                        result.push(' ');
                     }
-                },
-                Element::Function(element) => {
-                    if element.can_export(settings) {
-                        if document
-                            .functions
-                            .iter()
-                            .any(|item| item.name == element.name)
-                        {
-                            println!("Overwriting existing function: {}", element.name);
-                        }
-                        document.functions.push(element)
+                }
+                for n in chars.by_ref() {
+                    result.push(n);
+                    if last == '*' && n == '/' {
+                        break;
                     }
+                    last = n;
                 }
-                _ => {}
-            },
+            }
             _ => {}
         }
     }
+    result
 }
 
-fn parse_proxy(pair: Pair<Rule>, settings: &Settings, document: &mut Document) {
-    let mut doc_comments = None;
-    let mut tags = HashSet::new();
-    let mut content = String::new();
-    for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::doc_comment_lines => doc_comments = Some(parse_doc_comments(pair)),
-            Rule::proxy_tags => {
-                for pair in pair.into_inner() {
-                    tags.insert(parse_identifier(pair));
+#[allow(clippy::result_large_err)]
+fn parse_unreal_cpp_element(
+    content: &str,
+    document: &mut Document,
+    settings: &Settings,
+) -> Result<Element, Error<Rule>> {
+    let pair = UnrealCppHeaderParser::parse(Rule::element, content)?
+        .next()
+        .unwrap();
+    match pair.as_rule() {
+        Rule::element => Ok(parse_element(
+            pair,
+            Visibility::Public,
+            settings,
+            &Source::default(),
+            document,
+        )),
+        _ => unreachable!(),
+    }
+}
+
+/// Replaces already processed content with line breaks and spaces, so that lines and columns
+/// reported for the remaining content match the original source.
+fn masked_source(content: &str, range: Range<usize>) -> (String, usize) {
+    let prefix = &content[..range.start];
+    let lines = prefix.matches('\n').count();
+    let columns = prefix[(prefix.rfind('\n').map(|i| i + 1).unwrap_or_default())..]
+        .chars()
+        .count();
+    let mut result = "\n".repeat(lines);
+    result.push_str(&" ".repeat(columns));
+    let offset = result.len();
+    result.push_str(&content[range]);
+    (result, offset)
+}
+
+/// Scans code in given range and returns position right after first declaration found, that is
+/// either `;` or closed `{}` block (with optional `;`) at zero nesting level. If `block` is set,
+/// it returns position of `}` closing the block the scan has started in.
+fn find_declaration_end(content: &str, range: Range<usize>, block: bool) -> usize {
+    let bytes = content.as_bytes();
+    let mut depth = 0usize;
+    let mut parens = 0usize;
+    let mut index = range.start;
+    let mut line_start = true;
+    while index < range.end {
+        let c = bytes[index];
+        match c {
+            // Numbers might contain `'` digit separators.
+            b'0'..=b'9' if index == 0 || !is_identifier_byte(bytes[index - 1]) => {
+                while index < range.end
+                    && (is_identifier_byte(bytes[index]) || matches!(bytes[index], b'.' | b'\''))
+                {
+                    index += 1;
                 }
+                line_start = false;
+                continue;
             }
-            Rule::proxy_line_content => content.push_str(pair.as_str()),
+            b'"' | b'\'' => {
+                index += 1;
+                while index < range.end && bytes[index] != c && bytes[index] != b'\n' {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                while index < range.end && bytes[index] != b'\n' {
+                    index += 1;
+                }
+                continue;
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                index += 2;
+                while index < range.end && !content[index..].starts_with("*/") {
+                    index += 1;
+                }
+                index += 1;
+            }
+            b'#' if line_start => {
+                while index < range.end && bytes[index] != b'\n' {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+                continue;
+            }
+            b'(' | b'[' => parens += 1,
+            b')' | b']' => parens = parens.saturating_sub(1),
+            b'{' => depth += 1,
+            b'}' if depth == 0 => return index,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 && parens == 0 && !block {
+                    let rest = &content[(index + 1)..range.end];
+                    let trimmed = rest.trim_start();
+                    return if trimmed.starts_with(';') {
+                        range.end - trimmed.len() + 1
+                    } else {
+                        index + 1
+                    };
+                }
+            }
+            b';' if depth == 0 && parens == 0 && !block => return index + 1,
             _ => {}
         }
+        if c == b'\n' {
+            line_start = true;
+        } else if !c.is_ascii_whitespace() {
+            line_start = false;
+        }
+        index += 1;
     }
-    match parse_unreal_cpp_element(&content, document, settings) {
-        Element::Function(mut item) => {
-            if let Some(doc_comments) = doc_comments {
-                item.doc_comments = Some(doc_comments);
-                document.proxy_functions.push(Proxy { tags, item });
+    range.end
+}
+
+fn is_identifier_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_'
+}
+
+/// Reports `{` at given position, that is not closed until the end of scanned range.
+fn unclosed_block_diagnostic(content: &str, position: usize) -> Diagnostic {
+    let prefix = &content[..position];
+    let line_start = prefix.rfind('\n').map(|i| i + 1).unwrap_or_default();
+    Diagnostic {
+        line: prefix.matches('\n').count() + 1,
+        column: prefix[line_start..].chars().count() + 1,
+        message: "Unclosed block: missing `}`".to_owned(),
+    }
+}
+
+/// Skips declaration that could not be parsed and returns position where parsing can resume.
+fn skip_declaration(
+    content: &str,
+    range: Range<usize>,
+    error: Error<Rule>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> usize {
+    let end = find_declaration_end(content, range.clone(), false);
+    // Unmatched `}` has to be consumed in order to make progress.
+    let end = if end == range.start { end + 1 } else { end };
+    let end = end.min(range.end);
+    diagnostics.push(Diagnostic::from_error(error, &content[range.start..end]));
+    end
+}
+
+/// Parses scope content declaration by declaration, so that declarations that could not be
+/// parsed get skipped instead of failing the whole file.
+fn parse_scope_recovering(
+    content: &str,
+    range: Range<usize>,
+    namespace: Option<&str>,
+    document: &mut Document,
+    settings: &Settings,
+    source: &Source,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut position = range.start;
+    while position < range.end {
+        let (masked, offset) = masked_source(content, position..range.end);
+        if UnrealCppHeaderParser::parse(Rule::scope_end, &masked).is_ok() {
+            break;
+        }
+        let item = UnrealCppHeaderParser::parse(Rule::scope_item, &masked)
+            .map(|mut pairs| pairs.next().unwrap());
+        // Bare identifier matches keywords too, and doc comments or macros might precede type
+        // that failed to parse, so namespace and type headers take precedence.
+        let item = match item {
+            Ok(pair) if !is_bare_identifier(&pair) && !is_declaration_prefix(&pair) => {
+                position += pair.as_span().end() - offset;
+                parse_scope(pair, namespace, document, settings, source, diagnostics);
+                continue;
+            }
+            item => item,
+        };
+        if let Ok(mut pairs) = UnrealCppHeaderParser::parse(Rule::namespace_header, &masked) {
+            let pair = pairs.next().unwrap();
+            let start = position + pair.as_span().end() - offset;
+            let is_anonymous = is_anonymous_namespace(&pair);
+            let path = parse_namespace_header(pair, namespace);
+            let end = find_declaration_end(content, start..range.end, true);
+            if end == range.end {
+                diagnostics.push(unclosed_block_diagnostic(content, start - 1));
+            }
+            if !is_anonymous {
+                parse_scope_recovering(
+                    content,
+                    start..end,
+                    path.as_deref(),
+                    document,
+                    settings,
+                    source,
+                    diagnostics,
+                );
             }
+            position = skip_block_end(content, end..range.end);
+            continue;
         }
-        Element::Property(mut item) => {
-            if let Some(doc_comments) = doc_comments {
-                item.doc_comments = Some(doc_comments);
-                document.proxy_properties.push(Proxy { tags, item });
+        if let Ok(mut pairs) = UnrealCppHeaderParser::parse(Rule::struct_class_header, &masked) {
+            let pair = pairs.next().unwrap();
+            let start = position + pair.as_span().end() - offset;
+            let end = find_declaration_end(content, start..range.end, true);
+            if end == range.end {
+                diagnostics.push(unclosed_block_diagnostic(content, start - 1));
+            }
+            let element = parse_struct_class_recovering(
+                pair,
+                content,
+                start..end,
+                settings,
+                source,
+                document,
+                diagnostics,
+            );
+            add_scope_element(element, namespace, document, settings);
+            position = skip_block_end(content, end..range.end);
+            continue;
+        }
+        match item.and_then(standalone_identifier) {
+            Ok(pair) => {
+                position += pair.as_span().end() - offset;
+                parse_scope(pair, namespace, document, settings, source, diagnostics);
+            }
+            Err(error) => {
+                position = skip_declaration(content, position..range.end, error, diagnostics);
             }
         }
-        _ => {}
     }
 }
 
-fn parse_snippet(pair: Pair<Rule>, document: &mut Document) {
-    let mut id = None;
-    let mut content = None;
-    for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::identifier => id = Some(parse_identifier(pair)),
-            Rule::snippet_inner => content = Some(parse_snippet_inner(pair)),
-            _ => {}
-        }
-    }
-    if let (Some(id), Some(content)) = (id, content) {
-        if document.snippets.contains_key(&id) {
-            println!("Overwriting existing snippet: {}", id);
-        }
-        document.snippets.insert(id, content);
-    }
+fn is_bare_identifier(pair: &Pair<Rule>) -> bool {
+    pair.clone()
+        .into_inner()
+        .next()
+        .map(|pair| pair.as_rule() == Rule::identifier)
+        .unwrap_or_default()
 }
 
-fn parse_snippet_inner(pair: Pair<Rule>) -> String {
-    let level = pair
-        .as_str()
-        .lines()
-        .map(|line| line.chars().take_while(|c| c.is_whitespace()).count())
-        .min_by(|a, b| a.cmp(b))
-        .unwrap_or_default();
-    pair.as_str()
-        .lines()
-        .map(|line| line[level..].to_owned())
-        .collect::<Vec<_>>()
-        .join("\n")
+/// Tells if item is only doc comments or macro invocation, that might be part of the header of
+/// following namespace or type.
+fn is_declaration_prefix(pair: &Pair<Rule>) -> bool {
+    pair.clone()
+        .into_inner()
+        .next()
+        .map(|pair| matches!(pair.as_rule(), Rule::doc_comment_lines | Rule::macro_call))
+        .unwrap_or_default()
 }
 
-fn parse_doc_comments(pair: Pair<Rule>) -> String {
-    pair.as_str()
-        .lines()
-        .map(|line| {
-            line.find("///")
-                .map(|loc| line[(loc + 3)..].trim().to_owned())
-                .unwrap_or_default()
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
+/// Accepts bare identifier item only if it is the last token in its line (like parameterless
+/// macros), since otherwise it is just the first word of a declaration that failed to parse.
+#[allow(clippy::result_large_err)]
+fn standalone_identifier(pair: Pair<Rule>) -> Result<Pair<Rule>, Error<Rule>> {
+    if !is_bare_identifier(&pair) {
+        return Ok(pair);
+    }
+    let span = pair.as_span();
+    let rest = &span.get_input()[span.end()..];
+    let rest = rest.trim_start_matches([' ', '\t', '\r']);
+    if rest.is_empty() || rest.starts_with('\n') || rest.starts_with("//") {
+        return Ok(pair);
+    }
+    let identifier = pair.into_inner().next().unwrap();
+    Err(Error::new_from_pos(
+        ErrorVariant::CustomError {
+            message: format!("unexpected `{}`", identifier.as_str()),
+        },
+        identifier.as_span().start_pos(),
+    ))
 }
 
-enum Element {
-    None,
-    Enum(Enum),
-    StructClass(StructClass),
-    Property(Property),
-    Function(Function),
+/// Returns position after `}` closing the block and optional `;` following it.
+fn skip_block_end(content: &str, range: Range<usize>) -> usize {
+    let position = (range.start + 1).min(range.end);
+    let rest = &content[position..range.end];
+    let trimmed = rest.trim_start();
+    if trimmed.starts_with(';') {
+        range.end - trimmed.len() + 1
+    } else {
+        position
+    }
 }
 
-fn parse_element(
+/// Parses struct or class body member by member, skipping members that could not be parsed.
+fn parse_struct_class_recovering(
     pair: Pair<Rule>,
-    visibility: Visibility,
+    content: &str,
+    range: Range<usize>,
     settings: &Settings,
+    source: &Source,
     document: &mut Document,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Element {
-    let mut result = Element::None;
+    let condition = source.condition(&pair);
+    if is_compiled_out(&condition, settings) {
+        return Element::None;
+    }
+    let mut location = source.location(&pair);
+    location.end_line = content[..range.end].matches('\n').count() + 1;
     let mut doc_comments = None;
+    let mut directives = Directives::default();
+    let mut interface = false;
+    let mut result = StructClass::default();
     for pair in pair.into_inner() {
         match pair.as_rule() {
-            Rule::doc_comment_lines => doc_comments = Some(parse_doc_comments(pair)),
-            Rule::element_enum => result = Element::Enum(parse_element_enum(pair, &doc_comments)),
-            Rule::element_struct => {
-                result = Element::StructClass(parse_element_struct_class(
+            Rule::doc_comment_lines => (doc_comments, directives) = parse_doc_comment_lines(pair),
+            Rule::element_interface_header | Rule::element_class_header => {
+                interface = pair.as_rule() == Rule::element_interface_header;
+                result = parse_element_struct_class(
                     pair,
                     &doc_comments,
-                    StructClassMode::Struct,
+                    StructClassMode::Class,
                     settings,
+                    source,
                     document,
-                ));
+                );
             }
-            Rule::element_class => {
-                result = Element::StructClass(parse_element_struct_class(
+            Rule::element_struct_header => {
+                result = parse_element_struct_class(
                     pair,
                     &doc_comments,
-                    StructClassMode::Class,
+                    StructClassMode::Struct,
                     settings,
+                    source,
                     document,
-                ));
-            }
-            Rule::element_property => {
-                result = Element::Property(parse_element_property(pair, &doc_comments, visibility));
+                );
             }
-            Rule::element_function => {
-                result = Element::Function(parse_element_function(
+            _ => {}
+        }
+    }
+    result.condition = condition;
+    result.location = Some(location);
+    result.directives = directives;
+    let mut visibility = result.mode.default_visibility();
+    let mut position = range.start;
+    while position < range.end {
+        let (masked, offset) = masked_source(content, position..range.end);
+        if UnrealCppHeaderParser::parse(Rule::scope_end, &masked).is_ok() {
+            break;
+        }
+        match UnrealCppHeaderParser::parse(Rule::struct_class_body_item, &masked)
+            .map(|mut pairs| pairs.next().unwrap())
+            .and_then(standalone_identifier)
+        {
+            Ok(pair) => {
+                position += pair.as_span().end() - offset;
+                visibility = parse_struct_class_body(
                     pair,
-                    &doc_comments,
+                    &mut result,
                     visibility,
+                    settings,
+                    source,
                     document,
-                ));
+                );
+            }
+            Err(error) => {
+                position = skip_declaration(content, position..range.end, error, diagnostics);
             }
-            _ => {}
         }
     }
-    result
+    if interface {
+        Element::Interface(Interface::from_reflection_class(result))
+    } else {
+        Element::StructClass(result)
+    }
 }
 
-fn parse_specifiers(pair: Pair<Rule>) -> Specifiers {
-    let mut result = Specifiers::default();
-    if let Some(pair) = pair.into_inner().next() {
-        for pair in pair.into_inner() {
-            match pair.as_rule() {
-                Rule::specifier_single => result.attributes.push(Attribute::Single(
-                    parse_identifier(pair.into_inner().next().unwrap()),
-                )),
-                Rule::specifier_pair => {
-                    let mut pairs = pair.into_inner();
-                    result.attributes.push(Attribute::Pair {
-                        key: parse_identifier(pairs.next().unwrap()),
-                        value: parse_identifier(pairs.next().unwrap()),
-                    })
-                }
-                Rule::specifier_meta => parse_specifier_meta(pair, &mut result),
-                _ => {}
-            }
-        }
-    }
-    result
+fn parse_file(
+    pair: Pair<Rule>,
+    document: &mut Document,
+    settings: &Settings,
+    source: &Source,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    parse_scope(pair, None, document, settings, source, diagnostics);
 }
 
-fn parse_specifier_meta(pair: Pair<Rule>, result: &mut Specifiers) {
+fn parse_scope(
+    pair: Pair<Rule>,
+    namespace: Option<&str>,
+    document: &mut Document,
+    settings: &Settings,
+    source: &Source,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
     for pair in pair.into_inner() {
         match pair.as_rule() {
-            Rule::specifier_single => result.meta.push(Attribute::Single(parse_identifier(
-                pair.into_inner().next().unwrap(),
-            ))),
-            Rule::specifier_pair => {
-                let mut pairs = pair.into_inner();
-                result.meta.push(Attribute::Pair {
-                    key: parse_identifier(pairs.next().unwrap()),
-                    value: parse_identifier(pairs.next().unwrap()),
-                })
+            Rule::namespace => {
+                parse_namespace(pair, namespace, document, settings, source, diagnostics)
+            }
+            Rule::proxy => parse_proxy(pair, namespace, settings, source, document, diagnostics),
+            Rule::snippet => parse_snippet(pair, document),
+            Rule::element => {
+                let element = parse_element(pair, Visibility::Public, settings, source, document);
+                add_scope_element(element, namespace, document, settings);
             }
             _ => {}
         }
     }
 }
 
-fn parse_element_enum(pair: Pair<Rule>, doc_comments: &Option<String>) -> Enum {
-    let mut result = Enum {
-        doc_comments: doc_comments.to_owned(),
-        ..Default::default()
-    };
-    for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::uenum => result.specifiers = Some(parse_specifiers(pair)),
-            Rule::enum_signature => result.name = parse_enum_signature(pair),
-            Rule::enum_body => parse_enum_body(pair, &mut result),
-            _ => {}
+fn add_scope_element(
+    element: Element,
+    namespace: Option<&str>,
+    document: &mut Document,
+    settings: &Settings,
+) {
+    match element {
+        Element::Enum(mut element) if element.can_export(settings) => {
+            element.namespace = namespace.map(|v| v.to_owned());
+            let name = element.full_name();
+            if document.enums.iter().any(|item| item.full_name() == name) {
+                println!("Overwriting existing enum: {}", name);
+            }
+            document.enums.push(element)
         }
-    }
-    result
-}
-
-fn parse_enum_signature(pair: Pair<Rule>) -> String {
-    parse_identifier(pair.into_inner().next().unwrap())
-}
-
-fn parse_enum_body(pair: Pair<Rule>, result: &mut Enum) {
-    for pair in pair.into_inner() {
-        result.variants.push(parse_identifier(pair));
+        Element::StructClass(mut element) if element.can_export(settings) => {
+            element.namespace = namespace.map(|v| v.to_owned());
+            let name = element.full_name();
+            match element.mode {
+                StructClassMode::Struct => {
+                    if document.structs.iter().any(|item| item.full_name() == name) {
+                        println!("Overwriting existing struct: {}", name);
+                    }
+                    document.structs.push(element)
+                }
+                StructClassMode::Class => {
+                    if document.classes.iter().any(|item| item.full_name() == name) {
+                        println!("Overwriting existing class: {}", name);
+                    }
+                    document.classes.push(element)
+                }
+            }
+        }
+        Element::Delegate(mut element) if element.can_export(settings) => {
+            element.namespace = namespace.map(|v| v.to_owned());
+            let name = element.full_name();
+            if document
+                .delegates
+                .iter()
+                .any(|item| item.full_name() == name)
+            {
+                println!("Overwriting existing delegate: {}", name);
+            }
+            document.delegates.push(element)
+        }
+        Element::TypeAlias(mut element) if element.can_export(settings) => {
+            element.namespace = namespace.map(|v| v.to_owned());
+            let name = element.full_name();
+            if document
+                .type_aliases
+                .iter()
+                .any(|item| item.full_name() == name)
+            {
+                println!("Overwriting existing type alias: {}", name);
+            }
+            document.type_aliases.push(element)
+        }
+        Element::Interface(mut element) => {
+            element.namespace = namespace.map(|v| v.to_owned());
+            let name = element.full_name();
+            if document
+                .interfaces
+                .iter()
+                .any(|item| item.full_name() == name)
+            {
+                println!("Overwriting existing interface: {}", name);
+            }
+            document.interfaces.push(element)
+        }
+        Element::Properties(elements) => {
+            for element in elements {
+                let mut element = Variable::from_property(element);
+                if !element.can_export(settings) {
+                    continue;
+                }
+                element.namespace = namespace.map(|v| v.to_owned());
+                let name = element.full_name();
+                if document
+                    .variables
+                    .iter()
+                    .any(|item| item.full_name() == name)
+                {
+                    println!("Overwriting existing variable: {}", name);
+                }
+                document.variables.push(element)
+            }
+        }
+        Element::Function(mut element) if element.can_export(settings) => {
+            element.namespace = namespace.map(|v| v.to_owned());
+            let name = element.full_name();
+            if document
+                .functions
+                .iter()
+                .any(|item| item.full_name() == name)
+            {
+                println!("Overwriting existing function: {}", name);
+            }
+            document.functions.push(element)
+        }
+        _ => {}
     }
 }
 
-fn parse_element_struct_class(
+fn parse_namespace(
     pair: Pair<Rule>,
-    doc_comments: &Option<String>,
-    mode: StructClassMode,
-    settings: &Settings,
+    namespace: Option<&str>,
     document: &mut Document,
-) -> StructClass {
-    let mut result = StructClass {
-        mode,
-        doc_comments: doc_comments.to_owned(),
-        ..Default::default()
-    };
+    settings: &Settings,
+    source: &Source,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut path = namespace.map(|v| v.to_owned());
     for pair in pair.into_inner() {
         match pair.as_rule() {
-            Rule::ustruct | Rule::uclass => result.specifiers = Some(parse_specifiers(pair)),
-            Rule::struct_signature | Rule::class_signature => {
-                parse_struct_class_signature(pair, &mut result);
-            }
-            Rule::struct_class_body => {
-                parse_struct_class_body(
-                    pair,
-                    &mut result,
-                    mode.default_visibility(),
-                    settings,
-                    document,
-                );
-            }
+            Rule::namespace_header if is_anonymous_namespace(&pair) => return,
+            Rule::namespace_header => path = parse_namespace_header(pair, namespace),
+            Rule::namespace_body => parse_scope(
+                pair,
+                path.as_deref(),
+                document,
+                settings,
+                source,
+                diagnostics,
+            ),
             _ => {}
         }
     }
-    result
 }
 
-fn parse_struct_class_signature(pair: Pair<Rule>, result: &mut StructClass) {
+/// Tells if namespace header has no name, where content has internal linkage and is not
+/// documented.
+fn is_anonymous_namespace(pair: &Pair<Rule>) -> bool {
+    !pair
+        .clone()
+        .into_inner()
+        .any(|pair| pair.as_rule() == Rule::namespace_path)
+}
+
+fn parse_namespace_header(pair: Pair<Rule>, namespace: Option<&str>) -> Option<String> {
+    let mut path = namespace.map(|v| v.to_owned());
     for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::template_declaration => result.template = Some(parse_template_declaration(pair)),
-            Rule::api => result.api = Some(parse_identifier(pair)),
-            Rule::identifier => result.name = parse_identifier(pair),
-            Rule::inheritances => result.inherits = parse_inheritances(pair),
-            _ => {}
+        if pair.as_rule() == Rule::namespace_path {
+            for pair in pair.into_inner() {
+                if pair.as_rule() == Rule::identifier {
+                    let name = parse_identifier(pair);
+                    path = Some(qualified_name(path.as_deref(), &name));
+                }
+            }
         }
     }
+    path
 }
 
-fn parse_struct_class_body(
+/// Parses proxy content, that gets injected into items with matching inject tags. Proxied types
+/// without tags get documented in place instead. Tags with parameters make templates, parsed once
+/// matching macro invocations are found.
+fn parse_proxy(
     pair: Pair<Rule>,
-    result: &mut StructClass,
-    mut visibility: Visibility,
+    namespace: Option<&str>,
     settings: &Settings,
+    source: &Source,
     document: &mut Document,
+    diagnostics: &mut Vec<Diagnostic>,
 ) {
+    let (line, column) = pair.as_span().start_pos().line_col();
+    let location = Some(source.location(&pair));
+    let mut doc_comments = None;
+    let mut directives = Directives::default();
+    let mut tags = HashSet::new();
+    let mut templates = vec![];
+    let mut content = String::new();
     for pair in pair.into_inner() {
         match pair.as_rule() {
-            Rule::visibility => {
-                if let Some(v) = parse_visibility(pair) {
-                    visibility = v;
-                }
-            }
-            Rule::inject => {
+            Rule::doc_comment_lines => (doc_comments, directives) = parse_doc_comment_lines(pair),
+            Rule::proxy_tags => {
                 for pair in pair.into_inner() {
-                    result.injects.insert(parse_identifier(pair));
-                }
-            }
-            Rule::element => match parse_element(pair, visibility, settings, document) {
-                Element::Property(element) => {
-                    if element.can_export(settings) {
-                        result.properties.push(element);
+                    let mut tag = String::new();
+                    let mut parameters = None;
+                    for pair in pair.into_inner() {
+                        match pair.as_rule() {
+                            Rule::identifier => tag = parse_identifier(pair),
+                            Rule::proxy_parameters => {
+                                parameters = Some(pair.into_inner().map(parse_identifier).collect())
+                            }
+                            _ => {}
+                        }
                     }
-                }
-                Element::Function(element) => {
-                    if element.can_export(settings) {
-                        result.methods.push(element);
+                    match parameters {
+                        Some(parameters) => templates.push((tag, parameters)),
+                        None => {
+                            tags.insert(tag);
+                        }
                     }
                 }
-                _ => {}
-            },
+            }
+            Rule::proxy_line_content => content.push_str(pair.as_str()),
             _ => {}
         }
     }
-}
-
-fn parse_element_property(
-    pair: Pair<Rule>,
-    doc_comments: &Option<String>,
-    visibility: Visibility,
-) -> Property {
-    let mut result = Property {
-        doc_comments: doc_comments.to_owned(),
-        visibility,
-        ..Default::default()
+    let doc_comments = match doc_comments {
+        Some(doc_comments) if !directives.hidden => doc_comments,
+        _ => return,
     };
-    for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::uproperty => result.specifiers = Some(parse_specifiers(pair)),
-            Rule::property_signature => parse_property_signature(pair, &mut result),
-            _ => {}
+    let is_template = !templates.is_empty();
+    for (tag, parameters) in templates {
+        document.proxy_templates.push(ProxyTemplate {
+            tag,
+            parameters,
+            content: content.to_owned(),
+            doc_comments: doc_comments.to_owned(),
+            directives: directives.to_owned(),
+            location: location.to_owned(),
+        });
+    }
+    if !is_template || !tags.is_empty() {
+        if let Err(error) = add_proxy(
+            &content,
+            tags,
+            doc_comments,
+            directives,
+            location,
+            namespace,
+            settings,
+            document,
+        ) {
+            diagnostics.push(proxy_diagnostic(line, column, &content, error));
         }
     }
-    result
 }
 
-fn parse_property_signature(pair: Pair<Rule>, result: &mut Property) {
-    for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::value_type => result.value_type = parse_value_type(pair),
-            Rule::identifier => result.name = parse_identifier(pair),
-            Rule::property_array => result.array = parse_property_array(pair),
-            Rule::default_value => result.default_value = Some(parse_default_value(pair)),
-            Rule::staticness => result.is_static = true,
-            _ => {}
+/// Adds proxy with parsed content to document.
+#[allow(clippy::too_many_arguments, clippy::result_large_err)]
+fn add_proxy(
+    content: &str,
+    tags: HashSet<String>,
+    doc_comments: String,
+    directives: Directives,
+    location: Option<SourceLocation>,
+    namespace: Option<&str>,
+    settings: &Settings,
+    document: &mut Document,
+) -> Result<(), Error<Rule>> {
+    match parse_unreal_cpp_element(content, document, settings)? {
+        Element::Function(mut item) => {
+            item.doc_comments = Some(doc_comments);
+            item.location = location;
+            item.directives = directives;
+            document.proxy_functions.push(Proxy { tags, item });
+        }
+        Element::Properties(items) => {
+            for mut item in items {
+                item.doc_comments = Some(doc_comments.to_owned());
+                item.location = location.to_owned();
+                item.directives = directives.to_owned();
+                document.proxy_properties.push(Proxy {
+                    tags: tags.to_owned(),
+                    item,
+                });
+            }
+        }
+        Element::Enum(mut item) => {
+            item.doc_comments = Some(doc_comments);
+            item.location = location;
+            item.directives = directives;
+            if tags.is_empty() {
+                add_scope_element(Element::Enum(item), namespace, document, settings);
+            } else {
+                document.proxy_enums.push(Proxy { tags, item });
+            }
+        }
+        Element::StructClass(mut item) => {
+            item.doc_comments = Some(doc_comments);
+            item.location = location;
+            item.directives = directives;
+            if tags.is_empty() {
+                add_scope_element(Element::StructClass(item), namespace, document, settings);
+            } else {
+                document.proxy_structs.push(Proxy { tags, item });
+            }
+        }
+        Element::Delegate(mut item) => {
+            item.doc_comments = Some(doc_comments);
+            item.location = location;
+            item.directives = directives;
+            if tags.is_empty() {
+                add_scope_element(Element::Delegate(item), namespace, document, settings);
+            } else {
+                document.proxy_delegates.push(Proxy { tags, item });
+            }
         }
+        _ => {}
     }
+    Ok(())
 }
 
-fn parse_property_array(pair: Pair<Rule>) -> PropertyArray {
-    if let Some(pair) = pair.into_inner().next() {
-        PropertyArray::Sized(pair.as_str().trim().to_owned())
-    } else {
-        PropertyArray::Unsized
+fn proxy_diagnostic(line: usize, column: usize, content: &str, error: Error<Rule>) -> Diagnostic {
+    Diagnostic {
+        line,
+        column,
+        message: format!(
+            "Skipped unsupported proxy content `{}`: {}",
+            content.trim().lines().next().unwrap_or_default(),
+            error.variant.message()
+        ),
     }
 }
 
-fn parse_element_function(
-    pair: Pair<Rule>,
-    doc_comments: &Option<String>,
-    visibility: Visibility,
+/// Instantiates proxy templates for macros invoked in structs, classes and interfaces with
+/// arguments of these invocations, and injects them there. Returns diagnostics along with files
+/// of templates that could not be parsed.
+pub fn resolve_macro_injects(
     document: &mut Document,
-) -> Function {
-    let mut result = Function {
-        doc_comments: doc_comments.to_owned(),
-        visibility,
-        ..Default::default()
-    };
-    for pair in pair.into_inner() {
-        match pair.as_rule() {
-            Rule::ufunction => result.specifiers = Some(parse_specifiers(pair)),
-            Rule::function_signature | Rule::constructor_signature => {
-                parse_function_signature(pair, &mut result)
+    settings: &Settings,
+) -> Vec<(String, Diagnostic)> {
+    let mut result = vec![];
+    if document.proxy_templates.is_empty() {
+        return result;
+    }
+    let templates = std::mem::take(&mut document.proxy_templates);
+    let mut calls = document
+        .structs
+        .iter_mut()
+        .chain(document.classes.iter_mut())
+        .map(|item| (&mut item.injects, &item.macro_calls))
+        .chain(
+            document
+                .interfaces
+                .iter_mut()
+                .map(|item| (&mut item.injects, &item.macro_calls)),
+        )
+        .flat_map(|(injects, macro_calls)| {
+            macro_calls
+                .iter()
+                .filter(|call| templates.iter().any(|template| template.tag == call.name))
+                .map(|call| {
+                    injects.insert(call.signature());
+                    call.to_owned()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    calls.sort_by_key(|call| call.signature());
+    calls.dedup();
+    for call in calls {
+        for template in templates
+            .iter()
+            .filter(|template| template.tag == call.name)
+        {
+            let (content, doc_comments) = template.instantiate(&call.arguments);
+            let tags = HashSet::from([call.signature()]);
+            if let Err(error) = add_proxy(
+                &content,
+                tags,
+                doc_comments,
+                template.directives.to_owned(),
+                template.location.to_owned(),
+                None,
+                settings,
+                document,
+            ) {
+                let (file, line) = template
+                    .location
+                    .as_ref()
+                    .map(|location| (location.file.to_owned(), location.start_line))
+                    .unwrap_or_default();
+                result.push((file, proxy_diagnostic(line, 1, &content, error)));
             }
-            Rule::function_body => parse_function_body(pair, document),
-            _ => {}
         }
     }
     result
 }
 
-fn parse_function_signature(pair: Pair<Rule>, result: &mut Function) {
+fn parse_snippet(pair: Pair<Rule>, document: &mut Document) {
+    let mut id = None;
+    let mut content = None;
     for pair in pair.into_inner() {
         match pair.as_rule() {
-            Rule::template_declaration => result.template = Some(parse_template_declaration(pair)),
-            Rule::virtualness => result.is_virtual = true,
-            Rule::value_type => result.return_type = Some(parse_value_type(pair)),
-            Rule::operator | Rule::identifier => result.name = parse_identifier(pair),
-            Rule::function_arguments => parse_function_arguments(pair, result),
-            Rule::constness => result.is_const_this = true,
-            Rule::overrideness => result.is_override = true,
-            Rule::staticness => result.is_static = true,
+            Rule::identifier => id = Some(parse_identifier(pair)),
+            Rule::snippet_inner => content = Some(parse_snippet_inner(pair)),
             _ => {}
         }
     }
-}
-
-fn parse_function_arguments(pair: Pair<Rule>, result: &mut Function) {
-    for pair in pair.into_inner() {
-        if pair.as_rule() == Rule::function_argument {
-            result.arguments.push(parse_function_argument(pair));
+    if let (Some(id), Some(content)) = (id, content) {
+        if document.snippets.contains_key(&id) {
+            println!("Overwriting existing snippet: {}", id);
         }
+        document.snippets.insert(id, content);
     }
 }
 
-fn parse_function_argument(pair: Pair<Rule>) -> Argument {
-    let mut result = Argument::default();
+fn parse_snippet_inner(pair: Pair<Rule>) -> String {
+    let level = pair
+        .as_str()
+        .lines()
+        .map(|line| line.chars().take_while(|c| c.is_whitespace()).count())
+        .min_by(|a, b| a.cmp(b))
+        .unwrap_or_default();
+    pair.as_str()
+        .lines()
+        .map(|line| line[level..].to_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_doc_comments(pair: Pair<Rule>) -> String {
+    let mut result = vec![];
     for pair in pair.into_inner() {
         match pair.as_rule() {
-            Rule::doc_comment_lines => result.doc_comments = Some(parse_doc_comments(pair)),
-            Rule::value_type => result.value_type = parse_value_type(pair),
-            Rule::identifier => result.name = Some(parse_identifier(pair)),
-            Rule::default_value => result.default_value = Some(parse_default_value(pair)),
+            Rule::doc_comment_line => {
+                let line = pair.as_str();
+                result.push(
+                    line.find("///")
+                        .map(|loc| line[(loc + 3)..].trim().to_owned())
+                        .unwrap_or_default(),
+                );
+            }
+            Rule::doc_comment_block => result.extend(parse_doc_comment_block(pair)),
             _ => {}
         }
     }
-    result
+    result.join("\n")
 }
 
-fn parse_function_body(pair: Pair<Rule>, document: &mut Document) {
-    for pair in pair.into_inner() {
-        if pair.as_rule() == Rule::snippet {
-            parse_snippet(pair, document);
+/// Returns doc comments, unless there are only directives, along with directives.
+fn parse_doc_comment_lines(pair: Pair<Rule>) -> (Option<String>, Directives) {
+    let mut directives = Directives::default();
+    let mut documented = false;
+    for pair in pair.clone().into_inner() {
+        if pair.as_rule() != Rule::doc_directive {
+            documented = true;
+            continue;
+        }
+        for pair in pair.into_inner() {
+            let value = pair
+                .clone()
+                .into_inner()
+                .next()
+                .map(|pair| pair.as_str().trim().to_owned());
+            match pair.as_rule() {
+                Rule::doc_directive_hidden => directives.hidden = true,
+                Rule::doc_directive_group => directives.group = value,
+                Rule::doc_directive_alias => directives.aliases.extend(value),
+                _ => {}
+            }
         }
     }
+    let doc_comments = documented.then(|| parse_doc_comments(pair));
+    (doc_comments, directives)
 }
 
-fn parse_default_value(pair: Pair<Rule>) -> String {
-    pair.into_inner().next().unwrap().as_str().to_owned()
-}
-
-fn parse_value_type(pair: Pair<Rule>) -> String {
-    pair.as_str().trim().to_owned()
+/// Returns doc comments of part of signature, which can not be hidden, so directives are ignored.
+fn parse_signature_doc_comments(pair: Pair<Rule>) -> Option<String> {
+    parse_doc_comment_lines(pair).0
 }
 
-fn parse_template_declaration(pair: Pair<Rule>) -> String {
-    pair.as_str().trim().to_owned()
+/// Appends `///<` or `//!<` member comment to doc comments of preceding member.
+fn parse_trailing_doc_comment(pair: Pair<Rule>, doc_comments: &mut Option<String>) {
+    let content = pair
+        .into_inner()
+        .map(|pair| pair.as_str().trim())
+        .collect::<Vec<_>>()
+        .join("\n");
+    *doc_comments = Some(match doc_comments.take() {
+        Some(doc_comments) => format!("{}\n\n{}", doc_comments, content),
+        None => content,
+    });
 }
 
-fn parse_visibility(pair: Pair<Rule>) -> Option<Visibility> {
-    match pair.as_str() {
-        "private" => Some(Visibility::Private),
-        "protected" => Some(Visibility::Protected),
-        "public" => Some(Visibility::Public),
-        _ => None,
+fn parse_doc_comment_block(pair: Pair<Rule>) -> Vec<String> {
+    let content = pair.as_str();
+    let content = &content[3..(content.len() - 2)];
+    let mut lines = content
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').unwrap_or(line).trim().to_owned()
+        })
+        .collect::<Vec<_>>();
+    while lines
+        .first()
+        .map(|line| line.is_empty())
+        .unwrap_or_default()
+    {
+        lines.remove(0);
     }
-}
-
-fn parse_inheritances(pair: Pair<Rule>) -> Vec<(Visibility, String)> {
-    let mut result = vec![];
-    for pair in pair.into_inner() {
-        let mut pairs = pair.into_inner();
-        result.push((
-            parse_visibility(pairs.next().unwrap()).unwrap(),
-            parse_value_type(pairs.next().unwrap()),
-        ));
+    while lines.last().map(|line| line.is_empty()).unwrap_or_default() {
+        lines.pop();
     }
-    result
+    lines
 }
 
-fn parse_identifier(pair: Pair<Rule>) -> String {
-    pair.as_str().to_owned()
+enum Element {
+    None,
+    Enum(Enum),
+    StructClass(StructClass),
+    Interface(Interface),
+    Delegate(Delegate),
+    TypeAlias(TypeAlias),
+    Properties(Vec<Property>),
+    Function(Function),
 }
 
-#[test]
-fn test_parsing() {
-    let content = crate::read_file("resources/source/test.h").unwrap();
+fn parse_element(
+    pair: Pair<Rule>,
+    visibility: Visibility,
+    settings: &Settings,
+    source: &Source,
+    document: &mut Document,
+) -> Element {
+    let condition = source.condition(&pair);
+    if is_compiled_out(&condition, settings) {
+        return Element::None;
+    }
+    let location = Some(source.location(&pair));
+    let mut result = Element::None;
+    let mut doc_comments = None;
+    let mut directives = Directives::default();
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::doc_comment_lines => (doc_comments, directives) = parse_doc_comment_lines(pair),
+            Rule::element_enum => result = Element::Enum(parse_element_enum(pair, &doc_comments)),
+            Rule::element_struct => {
+                result = Element::StructClass(parse_element_struct_class(
+                    pair,
+                    &doc_comments,
+                    StructClassMode::Struct,
+                    settings,
+                    source,
+                    document,
+                ));
+            }
+            Rule::element_delegate => {
+                result = Element::Delegate(parse_element_delegate(pair, &doc_comments))
+            }
+            Rule::element_type_alias => {
+                result = Element::TypeAlias(parse_element_type_alias(pair, &doc_comments))
+            }
+            Rule::element_interface => {
+                result = Element::Interface(Interface::from_reflection_class(
+                    parse_element_struct_class(
+                        pair,
+                        &doc_comments,
+                        StructClassMode::Class,
+                        settings,
+                        source,
+                        document,
+                    ),
+                ));
+            }
+            Rule::element_class => {
+                result = Element::StructClass(parse_element_struct_class(
+                    pair,
+                    &doc_comments,
+                    StructClassMode::Class,
+                    settings,
+                    source,
+                    document,
+                ));
+            }
+            Rule::element_property => {
+                result = Element::Properties(parse_element_property(
+                    pair,
+                    &doc_comments,
+                    visibility,
+                    settings,
+                ));
+            }
+            Rule::element_function => {
+                result = Element::Function(parse_element_function(
+                    pair,
+                    &doc_comments,
+                    visibility,
+                    document,
+                ));
+            }
+            _ => {}
+        }
+    }
+    match &mut result {
+        Element::None => {}
+        Element::Enum(item) => {
+            item.condition = condition;
+            item.location = location;
+            item.directives = directives;
+        }
+        Element::StructClass(item) => {
+            item.condition = condition;
+            item.location = location;
+            item.directives = directives;
+        }
+        Element::Interface(item) => {
+            item.condition = condition;
+            item.location = location;
+            item.directives = directives;
+        }
+        Element::Delegate(item) => {
+            item.condition = condition;
+            item.location = location;
+            item.directives = directives;
+        }
+        Element::TypeAlias(item) => {
+            item.condition = condition;
+            item.location = location;
+            item.directives = directives;
+        }
+        Element::Properties(items) => {
+            for item in items {
+                item.condition = condition.to_owned();
+                item.location = location.to_owned();
+                item.directives = directives.to_owned();
+            }
+        }
+        Element::Function(item) => {
+            item.condition = condition;
+            item.location = location;
+            item.directives = directives;
+        }
+    }
+    result
+}
+
+/// Tells if element under given condition gets compiled out with defines set in settings.
+fn is_compiled_out(condition: &Option<String>, settings: &Settings) -> bool {
+    match (condition, &settings.defines) {
+        (Some(condition), Some(defines)) => {
+            preprocessor::evaluate(condition, defines, true) == Some(0)
+        }
+        _ => false,
+    }
+}
+
+/// Returns API export macro, unless it is not one of known export macros set in settings.
+fn parse_api(pair: Pair<Rule>, settings: &Settings) -> Option<String> {
+    let name = parse_identifier(pair);
+    if settings.is_api_macro(&name) {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+fn parse_specifiers(pair: Pair<Rule>) -> Specifiers {
+    let mut result = Specifiers::default();
+    if let Some(pair) = pair.into_inner().next() {
+        for pair in pair.into_inner() {
+            match pair.as_rule() {
+                Rule::specifier_single => result.attributes.push(Attribute::Single(
+                    parse_identifier(pair.into_inner().next().unwrap()),
+                )),
+                Rule::specifier_pair => {
+                    let mut pairs = pair.into_inner();
+                    result.attributes.push(Attribute::Pair {
+                        key: parse_identifier(pairs.next().unwrap()),
+                        value: parse_identifier(pairs.next().unwrap()),
+                    })
+                }
+                Rule::specifier_meta => parse_specifier_meta(pair, &mut result),
+                _ => {}
+            }
+        }
+    }
+    result
+}
+
+fn parse_specifier_meta(pair: Pair<Rule>, result: &mut Specifiers) {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::specifier_single => result.meta.push(Attribute::Single(parse_identifier(
+                pair.into_inner().next().unwrap(),
+            ))),
+            Rule::specifier_pair => {
+                let mut pairs = pair.into_inner();
+                result.meta.push(Attribute::Pair {
+                    key: parse_identifier(pairs.next().unwrap()),
+                    value: parse_identifier(pairs.next().unwrap()),
+                })
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_deprecation(pair: Pair<Rule>) -> Deprecation {
+    let mut result = Deprecation::default();
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::deprecation_version => result.version = Some(pair.as_str().to_owned()),
+            Rule::deprecation_message => {
+                result.message = Some(pair.into_inner().map(|pair| pair.as_str()).collect());
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Falls back to deprecation declared with reflection specifiers.
+fn resolve_deprecation(deprecated: &mut Option<Deprecation>, specifiers: &Option<Specifiers>) {
+    if deprecated.is_none() {
+        *deprecated = specifiers
+            .as_ref()
+            .and_then(|specifiers| specifiers.deprecation());
+    }
+}
+
+fn parse_element_enum(pair: Pair<Rule>, doc_comments: &Option<String>) -> Enum {
+    let mut result = Enum {
+        doc_comments: doc_comments.to_owned(),
+        ..Default::default()
+    };
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::uenum => result.specifiers = Some(parse_specifiers(pair)),
+            Rule::enum_legacy => parse_enum_legacy(pair, &mut result),
+            Rule::enum_signature => parse_enum_signature(pair, &mut result),
+            Rule::enum_body => parse_enum_body(pair, &mut result),
+            _ => {}
+        }
+    }
+    resolve_deprecation(&mut result.deprecated, &result.specifiers);
+    result
+}
+
+fn parse_enum_legacy(pair: Pair<Rule>, result: &mut Enum) {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::identifier => result.name = parse_identifier(pair),
+            Rule::doc_comment_lines if result.doc_comments.is_none() => {
+                let (doc_comments, directives) = parse_doc_comment_lines(pair);
+                result.doc_comments = doc_comments;
+                result.directives.merge(directives);
+            }
+            Rule::enum_signature => {
+                let name = std::mem::take(&mut result.name);
+                parse_enum_signature(pair, result);
+                result.legacy_name = Some(std::mem::replace(&mut result.name, name));
+            }
+            Rule::enum_body => parse_enum_body(pair, result),
+            _ => {}
+        }
+    }
+}
+
+fn parse_enum_signature(pair: Pair<Rule>, result: &mut Enum) {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::enum_scope => result.scope = Some(pair.as_str().to_owned()),
+            Rule::deprecation => result.deprecated = Some(parse_deprecation(pair)),
+            Rule::identifier => result.name = parse_identifier(pair),
+            Rule::enum_underlying_type => result.underlying_type = Some(parse_value_type(pair)),
+            _ => {}
+        }
+    }
+}
+
+fn parse_enum_body(pair: Pair<Rule>, result: &mut Enum) {
+    let mut hidden = false;
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::enum_variant => {
+                let (variant, directives) = parse_enum_variant(pair);
+                hidden = directives.hidden;
+                if !hidden {
+                    result.variants.push(variant);
+                }
+            }
+            Rule::trailing_doc_comment if !hidden => {
+                if let Some(variant) = result.variants.last_mut() {
+                    parse_trailing_doc_comment(pair, &mut variant.doc_comments);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_enum_variant(pair: Pair<Rule>) -> (EnumVariant, Directives) {
+    let mut result = EnumVariant::default();
+    let mut directives = Directives::default();
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::doc_comment_lines => {
+                (result.doc_comments, directives) = parse_doc_comment_lines(pair);
+            }
+            Rule::identifier => result.name = parse_identifier(pair),
+            Rule::enum_variant_value => result.value = Some(pair.as_str().trim().to_owned()),
+            Rule::umeta => result.specifiers = Some(parse_specifiers(pair)),
+            Rule::trailing_doc_comment => {
+                parse_trailing_doc_comment(pair, &mut result.doc_comments)
+            }
+            _ => {}
+        }
+    }
+    (result, directives)
+}
+
+fn parse_element_delegate(pair: Pair<Rule>, doc_comments: &Option<String>) -> Delegate {
+    let mut result = Delegate {
+        doc_comments: doc_comments.to_owned(),
+        ..Default::default()
+    };
+    let mut arguments = vec![];
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::delegate_macro => {
+                for pair in pair.into_inner() {
+                    match pair.as_rule() {
+                        Rule::delegate_dynamic => result.is_dynamic = true,
+                        Rule::delegate_multicast => result.is_multicast = true,
+                        Rule::delegate_sparse => result.is_sparse = true,
+                        Rule::delegate_retval => result.return_type = Some(Default::default()),
+                        _ => {}
+                    }
+                }
+            }
+            Rule::deprecation => result.deprecated = Some(parse_deprecation(pair)),
+            Rule::delegate_arguments => {
+                arguments = pair.into_inner().map(parse_value_type).collect::<Vec<_>>();
+            }
+            _ => {}
+        }
+    }
+    let mut arguments = arguments.into_iter();
+    if result.return_type.is_some() {
+        result.return_type = arguments.next();
+    }
+    result.name = arguments.next().unwrap_or_default();
+    if result.is_sparse {
+        if let (Some(owner), Some(property)) = (arguments.next(), arguments.next()) {
+            result.sparse_owner = Some((owner, property));
+        }
+    }
+    while let Some(value_type) = arguments.next() {
+        let name = if result.is_dynamic {
+            arguments.next()
+        } else {
+            None
+        };
+        result.arguments.push(Argument {
+            name,
+            value_type,
+            ..Default::default()
+        });
+    }
+    result
+}
+
+fn parse_element_type_alias(pair: Pair<Rule>, doc_comments: &Option<String>) -> TypeAlias {
+    let mut result = TypeAlias {
+        doc_comments: doc_comments.to_owned(),
+        ..Default::default()
+    };
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::template_declaration => result.template = Some(parse_template_declaration(pair)),
+            Rule::identifier => result.name = parse_identifier(pair),
+            Rule::deprecation => result.deprecated = Some(parse_deprecation(pair)),
+            Rule::type_alias_target => result.target = pair.as_str().trim().to_owned(),
+            Rule::type_alias_typedef => {
+                result.is_typedef = true;
+                for pair in pair.into_inner() {
+                    match pair.as_rule() {
+                        Rule::value_type => result.target = parse_value_type(pair),
+                        Rule::identifier => result.name = parse_identifier(pair),
+                        _ => {}
+                    }
+                }
+            }
+            Rule::trailing_doc_comment => {
+                parse_trailing_doc_comment(pair, &mut result.doc_comments)
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn parse_element_struct_class(
+    pair: Pair<Rule>,
+    doc_comments: &Option<String>,
+    mode: StructClassMode,
+    settings: &Settings,
+    source: &Source,
+    document: &mut Document,
+) -> StructClass {
+    let mut result = StructClass {
+        mode,
+        doc_comments: doc_comments.to_owned(),
+        ..Default::default()
+    };
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::ustruct | Rule::uclass | Rule::uinterface => {
+                let specifiers = parse_specifiers(pair);
+                result.is_abstract = specifiers.attributes.iter().any(|attribute| {
+                    matches!(attribute, Attribute::Single(name) if name.eq_ignore_ascii_case("Abstract"))
+                });
+                result.specifiers = Some(specifiers);
+            }
+            Rule::struct_signature | Rule::class_signature => {
+                parse_struct_class_signature(pair, &mut result, settings);
+            }
+            Rule::struct_class_body => {
+                parse_struct_class_body(
+                    pair,
+                    &mut result,
+                    mode.default_visibility(),
+                    settings,
+                    source,
+                    document,
+                );
+            }
+            _ => {}
+        }
+    }
+    resolve_deprecation(&mut result.deprecated, &result.specifiers);
+    result
+}
+
+fn parse_struct_class_signature(pair: Pair<Rule>, result: &mut StructClass, settings: &Settings) {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::template_declaration => result.template = Some(parse_template_declaration(pair)),
+            Rule::api => result.api = parse_api(pair, settings),
+            Rule::deprecation => result.deprecated = Some(parse_deprecation(pair)),
+            Rule::identifier => result.name = parse_identifier(pair),
+            Rule::template_specialization => {
+                result.specialization = Some(parse_template_specialization(pair))
+            }
+            Rule::inheritances => result.inherits = parse_inheritances(pair),
+            _ => {}
+        }
+    }
+}
+
+fn parse_struct_class_body(
+    pair: Pair<Rule>,
+    result: &mut StructClass,
+    mut visibility: Visibility,
+    settings: &Settings,
+    source: &Source,
+    document: &mut Document,
+) -> Visibility {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::visibility => {
+                if let Some(v) = parse_visibility(pair) {
+                    visibility = v;
+                }
+            }
+            Rule::inject => {
+                for pair in pair.into_inner() {
+                    result.injects.insert(parse_identifier(pair));
+                }
+            }
+            Rule::macro_call => result.macro_calls.push(parse_macro_call(pair)),
+            Rule::element if constructor_macro_call(&pair, &result.name).is_some() => {
+                result
+                    .macro_calls
+                    .extend(constructor_macro_call(&pair, &result.name));
+            }
+            Rule::element => match parse_element(pair, visibility, settings, source, document) {
+                Element::Properties(elements) => {
+                    result.properties.extend(
+                        elements
+                            .into_iter()
+                            .filter(|element| element.can_export(settings)),
+                    );
+                }
+                Element::Function(element) => {
+                    if element.is_pure_virtual {
+                        result.is_abstract = true;
+                    }
+                    if element.can_export(settings) {
+                        result.methods.push(element);
+                    }
+                }
+                Element::Enum(element)
+                    if visibility.can_export(settings) && element.can_export(settings) =>
+                {
+                    result.nested_enums.push(element);
+                }
+                Element::StructClass(element)
+                    if visibility.can_export(settings) && element.can_export(settings) =>
+                {
+                    result.nested_structs.push(element);
+                }
+                Element::TypeAlias(element)
+                    if visibility.can_export(settings) && element.can_export(settings) =>
+                {
+                    result.nested_type_aliases.push(element);
+                }
+                Element::Delegate(element)
+                    if visibility.can_export(settings) && element.can_export(settings) =>
+                {
+                    result.nested_delegates.push(element);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    visibility
+}
+
+fn parse_macro_call(pair: Pair<Rule>) -> MacroCall {
+    let mut result = MacroCall::default();
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::path => result.name = pair.as_str().trim().to_owned(),
+            Rule::call_arguments => {
+                result.arguments = pair
+                    .into_inner()
+                    .map(|pair| pair.as_str().trim().to_owned())
+                    .collect()
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Returns macro invocation that got parsed as constructor declaration not named after its owner.
+fn constructor_macro_call(pair: &Pair<Rule>, owner: &str) -> Option<MacroCall> {
+    let pair = pair
+        .clone()
+        .into_inner()
+        .find(|pair| pair.as_rule() == Rule::element_function)?;
+    let mut pairs = pair.into_inner();
+    let pair = pairs.next()?;
+    if pair.as_rule() != Rule::constructor_signature || pairs.next().is_some() {
+        return None;
+    }
+    let mut result = MacroCall::default();
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::identifier => result.name = parse_identifier(pair),
+            Rule::function_arguments => {
+                result.arguments = pair
+                    .into_inner()
+                    .map(|pair| pair.as_str().trim().to_owned())
+                    .collect()
+            }
+            _ => return None,
+        }
+    }
+    (result.name != owner).then_some(result)
+}
+
+/// Returns property per declarator, all sharing the same type, specifiers and doc comments.
+fn parse_element_property(
+    pair: Pair<Rule>,
+    doc_comments: &Option<String>,
+    visibility: Visibility,
+    settings: &Settings,
+) -> Vec<Property> {
+    let mut template = Property {
+        doc_comments: doc_comments.to_owned(),
+        visibility,
+        ..Default::default()
+    };
+    let mut result = vec![];
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::uproperty => template.specifiers = Some(parse_specifiers(pair)),
+            Rule::property_signature => {
+                result = parse_property_signature(pair, &mut template, settings)
+            }
+            Rule::trailing_doc_comment => {
+                for item in &mut result {
+                    parse_trailing_doc_comment(pair.clone(), &mut item.doc_comments);
+                }
+            }
+            _ => {}
+        }
+    }
+    for item in &mut result {
+        resolve_deprecation(&mut item.deprecated, &item.specifiers);
+    }
+    result
+}
+
+fn parse_property_signature(
+    pair: Pair<Rule>,
+    template: &mut Property,
+    settings: &Settings,
+) -> Vec<Property> {
+    let mut result = vec![];
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::value_type => template.value_type = parse_value_type(pair),
+            Rule::api => template.api = parse_api(pair, settings),
+            Rule::externness => template.is_extern = true,
+            Rule::staticness => template.is_static = true,
+            Rule::inlineness => template.is_inline = true,
+            Rule::constexprness => template.is_constexpr = true,
+            Rule::thread_localness => template.is_thread_local = true,
+            Rule::mutableness => template.is_mutable = true,
+            Rule::deprecation => template.deprecated = Some(parse_deprecation(pair)),
+            Rule::property_declarator => {
+                let mut item = template.to_owned();
+                parse_property_declarator(pair, &mut item);
+                result.push(item);
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn parse_property_declarator(pair: Pair<Rule>, result: &mut Property) {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::identifier => result.name = parse_identifier(pair),
+            Rule::property_array => result.array = parse_property_array(pair),
+            Rule::property_bitfield => {
+                result.bit_width = pair
+                    .into_inner()
+                    .next()
+                    .map(|pair| pair.as_str().trim().to_owned())
+            }
+            Rule::default_value => result.default_value = Some(parse_default_value(pair)),
+            Rule::brace_initializer => {
+                result.default_value = Some(pair.as_str().to_owned());
+                result.is_brace_initialized = true;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_property_array(pair: Pair<Rule>) -> PropertyArray {
+    if let Some(pair) = pair.into_inner().next() {
+        PropertyArray::Sized(pair.as_str().trim().to_owned())
+    } else {
+        PropertyArray::Unsized
+    }
+}
+
+fn parse_element_function(
+    pair: Pair<Rule>,
+    doc_comments: &Option<String>,
+    visibility: Visibility,
+    document: &mut Document,
+) -> Function {
+    let mut result = Function {
+        doc_comments: doc_comments.to_owned(),
+        visibility,
+        ..Default::default()
+    };
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::ufunction => result.specifiers = Some(parse_specifiers(pair)),
+            Rule::function_signature | Rule::constructor_signature => {
+                parse_function_signature(pair, &mut result)
+            }
+            Rule::function_defaulted => result.is_defaulted = true,
+            Rule::function_deleted => result.is_deleted = true,
+            Rule::function_pure => result.is_pure_virtual = true,
+            Rule::function_body => parse_function_body(pair, document),
+            Rule::trailing_doc_comment => {
+                parse_trailing_doc_comment(pair, &mut result.doc_comments)
+            }
+            _ => {}
+        }
+    }
+    resolve_deprecation(&mut result.deprecated, &result.specifiers);
+    result
+}
+
+fn parse_function_signature(pair: Pair<Rule>, result: &mut Function) {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::template_declaration => result.template = Some(parse_template_declaration(pair)),
+            Rule::virtualness => result.is_virtual = true,
+            Rule::value_type => result.return_type = Some(parse_value_type(pair)),
+            Rule::operator | Rule::identifier | Rule::destructor_name => {
+                result.name = parse_identifier(pair)
+            }
+            Rule::function_template => {
+                result.specialization = pair
+                    .into_inner()
+                    .next()
+                    .map(|pair| parse_template_specialization(pair));
+            }
+            Rule::function_arguments => parse_function_arguments(pair, result),
+            Rule::constness => result.is_const_this = true,
+            Rule::overrideness => result.is_override = true,
+            Rule::finalness => result.is_final = true,
+            Rule::staticness => result.is_static = true,
+            Rule::inlineness => result.is_inline = true,
+            Rule::forceinlineness => result.is_force_inline = true,
+            Rule::explicitness => result.is_explicit = true,
+            Rule::constexprness => result.is_constexpr = true,
+            Rule::nodiscardness => result.nodiscard = Some(pair.as_str().to_owned()),
+            Rule::deprecation => result.deprecated = Some(parse_deprecation(pair)),
+            Rule::ref_qualifier => result.ref_qualifier = Some(pair.as_str().to_owned()),
+            Rule::noexceptness => result.noexcept = Some(pair.as_str().trim().to_owned()),
+            Rule::trailing_return_type => {
+                result.trailing_return_type =
+                    pair.into_inner().next().map(|pair| parse_value_type(pair));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_function_arguments(pair: Pair<Rule>, result: &mut Function) {
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::function_argument => result.arguments.push(parse_function_argument(pair)),
+            Rule::trailing_doc_comment => {
+                if let Some(argument) = result.arguments.last_mut() {
+                    parse_trailing_doc_comment(pair, &mut argument.doc_comments);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_function_argument(pair: Pair<Rule>) -> Argument {
+    let mut result = Argument::default();
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::doc_comment_lines => result.doc_comments = parse_signature_doc_comments(pair),
+            Rule::uparam => result.specifiers = Some(parse_specifiers(pair)),
+            Rule::value_type => result.value_type = parse_value_type(pair),
+            Rule::identifier => result.name = Some(parse_identifier(pair)),
+            Rule::default_value => result.default_value = Some(parse_default_value(pair)),
+            Rule::trailing_doc_comment => {
+                parse_trailing_doc_comment(pair, &mut result.doc_comments)
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn parse_function_body(pair: Pair<Rule>, document: &mut Document) {
+    for pair in pair.into_inner() {
+        if pair.as_rule() == Rule::snippet {
+            parse_snippet(pair, document);
+        }
+    }
+}
+
+fn parse_default_value(pair: Pair<Rule>) -> String {
+    pair.into_inner().next().unwrap().as_str().to_owned()
+}
+
+fn parse_value_type(pair: Pair<Rule>) -> String {
+    pair.as_str().trim().to_owned()
+}
+
+fn parse_template_declaration(pair: Pair<Rule>) -> Template {
+    let mut result = Template::default();
+    for pair in pair.into_inner() {
+        if pair.as_rule() == Rule::template_declaration_arguments {
+            for pair in pair.into_inner() {
+                match pair.as_rule() {
+                    Rule::template_declaration_argument => {
+                        result.parameters.push(parse_template_parameter(pair));
+                    }
+                    Rule::trailing_doc_comment => {
+                        if let Some(parameter) = result.parameters.last_mut() {
+                            parse_trailing_doc_comment(pair, &mut parameter.doc_comments);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    result
+}
+
+fn parse_template_parameter(pair: Pair<Rule>) -> TemplateParameter {
+    let mut result = TemplateParameter::default();
+    for pair in pair.into_inner() {
+        match pair.as_rule() {
+            Rule::doc_comment_lines => result.doc_comments = parse_signature_doc_comments(pair),
+            Rule::template_declaration_type => {
+                let mut value_type = vec![];
+                for pair in pair.into_inner() {
+                    match pair.as_rule() {
+                        Rule::template_declaration => {
+                            value_type.push(parse_template_declaration(pair).signature())
+                        }
+                        Rule::dependentness => value_type.push(pair.as_str().to_owned()),
+                        Rule::unpackness => result.kind = TemplateParameterKind::Pack,
+                        Rule::identifier => result.name = Some(parse_identifier(pair)),
+                        _ => {}
+                    }
+                }
+                result.value_type = value_type.join(" ");
+            }
+            Rule::template_declaration_constant => {
+                result.kind = TemplateParameterKind::NonType;
+                for pair in pair.into_inner() {
+                    match pair.as_rule() {
+                        Rule::value_type => {
+                            let value_type = parse_value_type(pair);
+                            result.value_type = match value_type.strip_suffix("...") {
+                                Some(value_type) => {
+                                    result.kind = TemplateParameterKind::Pack;
+                                    value_type.trim().to_owned()
+                                }
+                                None => value_type,
+                            };
+                        }
+                        Rule::identifier => result.name = Some(parse_identifier(pair)),
+                        _ => {}
+                    }
+                }
+            }
+            Rule::template_declaration_default => {
+                result.default_value = Some(parse_default_value(pair));
+            }
+            Rule::trailing_doc_comment => {
+                parse_trailing_doc_comment(pair, &mut result.doc_comments)
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn parse_template_specialization(pair: Pair<Rule>) -> String {
+    pair.into_inner()
+        .map(|pair| pair.as_str().trim().to_owned())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn parse_visibility(pair: Pair<Rule>) -> Option<Visibility> {
+    match pair.as_str() {
+        "private" => Some(Visibility::Private),
+        "protected" => Some(Visibility::Protected),
+        "public" => Some(Visibility::Public),
+        _ => None,
+    }
+}
+
+fn parse_inheritances(pair: Pair<Rule>) -> Vec<(Visibility, String)> {
+    let mut result = vec![];
+    for pair in pair.into_inner() {
+        let mut pairs = pair.into_inner();
+        result.push((
+            parse_visibility(pairs.next().unwrap()).unwrap(),
+            parse_value_type(pairs.next().unwrap()),
+        ));
+    }
+    result
+}
+
+fn parse_identifier(pair: Pair<Rule>) -> String {
+    pair.as_str().to_owned()
+}
+
+#[test]
+fn test_parsing() {
+    let config = crate::read_file("resources/UnrealDoc.toml").unwrap();
+    let settings = toml::from_str::<crate::config::Config>(&config)
+        .unwrap()
+        .settings;
+    let content = crate::read_file("resources/source/test.h").unwrap();
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("test.h", &content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+}
+
+#[test]
+fn test_interfaces() {
+    let content = r#"
+UINTERFACE(Blueprintable, CannotImplementInterfaceInBlueprint)
+class UFoo : public UInterface
+{
+    GENERATED_BODY()
+};
+
+#if WITH_EDITOR
+/// Foo interface.
+class API IFoo
+{
+    GENERATED_BODY()
+
+public:
+    /// Method.
+    virtual void Bar();
+};
+#endif
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    document.resolve_interfaces(&Default::default());
+    assert!(document.classes.is_empty());
+    assert_eq!(document.interfaces.len(), 1);
+    let item = &document.interfaces[0];
+    assert_eq!(item.name, "Foo");
+    assert_eq!(item.api.as_deref(), Some("API"));
+    assert_eq!(item.specifiers.as_ref().unwrap().attributes.len(), 2);
+    assert_eq!(item.condition.as_deref(), Some("WITH_EDITOR"));
+    assert_eq!(item.methods.len(), 1);
+}
+
+#[test]
+fn test_delegates() {
+    let content = r#"
+/// Delegate.
+DECLARE_DYNAMIC_MULTICAST_SPARSE_DELEGATE_OneParam(FOnSparse, AActor, OnSparse, float, Value);
+/// Delegate.
+DECLARE_DELEGATE_RetVal_TwoParams(bool, FOnNative, const TMap<int32, float>&, int32);
+/// Delegate.
+DECLARE_MULTICAST_DELEGATE(FOnSimple);
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(document.delegates.len(), 3);
+    let item = &document.delegates[0];
+    assert!(item.is_dynamic && item.is_multicast && item.is_sparse);
+    assert_eq!(
+        item.sparse_owner,
+        Some(("AActor".to_owned(), "OnSparse".to_owned()))
+    );
+    assert_eq!(item.arguments[0].name.as_deref(), Some("Value"));
+    let item = &document.delegates[1];
+    assert_eq!(item.return_type.as_deref(), Some("bool"));
+    assert_eq!(item.arguments.len(), 2);
+    assert_eq!(item.macro_name(), "DECLARE_DELEGATE_RetVal_TwoParams");
+    let item = &document.delegates[2];
+    assert_eq!(item.macro_name(), "DECLARE_MULTICAST_DELEGATE");
+}
+
+#[test]
+fn test_enums() {
+    let content = r#"
+/// Enum.
+enum class EA : int32 { A };
+/// Enum.
+enum EB { B };
+/// Enum.
+enum EC : uint8 { C };
+/// Enum.
+enum struct EE : int32 { E };
+/// Enum.
+UENUM()
+namespace ED
+{
+    enum Type
+    {
+        D
+    };
+}
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let signatures = document
+        .enums
+        .iter()
+        .map(|item| item.signature())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        vec![
+            "enum class EA : int32 {\n    A\n};",
+            "enum EB {\n    B\n};",
+            "enum EC : uint8 {\n    C\n};",
+            "enum struct EE : int32 {\n    E\n};",
+            "namespace ED {\n    enum Type {\n        D\n    };\n}",
+        ]
+    );
+}
+
+#[test]
+fn test_enum_variants() {
+    let content = r#"
+/// Enum.
+UENUM(BlueprintType)
+enum class EFoo : uint8
+{
+    /// First variant.
+    A = 4 UMETA(DisplayName = "Alpha"),
+    B UMETA(Hidden),
+    C,
+};
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let variants = &document.enums[0].variants;
+    assert_eq!(variants.len(), 3);
+    let variant = &variants[0];
+    assert_eq!(variant.name, "A");
+    assert_eq!(variant.value.as_deref(), Some("4"));
+    assert_eq!(variant.display_name(), Some("Alpha"));
+    assert_eq!(variant.doc_comments.as_deref(), Some("First variant."));
+    assert_eq!(variant.signature(), "A = 4");
+    let variant = &variants[1];
+    assert_eq!(variant.name, "B");
+    assert_eq!(variant.value, None);
+    assert_eq!(variant.display_name(), None);
+    assert!(matches!(
+        variant.specifiers.as_ref().unwrap().attributes.as_slice(),
+        [Attribute::Single(name)] if name == "Hidden"
+    ));
+    assert_eq!(variant.doc_comments, None);
+    let variant = &variants[2];
+    assert_eq!(variant.name, "C");
+    assert!(variant.specifiers.is_none());
+}
+
+#[test]
+fn test_doc_comment_blocks() {
+    let content = r#"
+/**
+ * Function.
+ *
+ * More info.
+ */
+void A(/** Argument. */ int X);
+
+/*** Banner ***/
+/** Function. */
+void B(const char* Path = "/**/*.h");
+"#;
+    let settings = Settings {
+        block_doc_comments: true,
+        ..Default::default()
+    };
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(document.functions.len(), 2);
+    assert_eq!(
+        document.functions[0].doc_comments.as_deref(),
+        Some("Function.\n\nMore info.")
+    );
+    assert_eq!(
+        document.functions[0].arguments[0].doc_comments.as_deref(),
+        Some("Argument.")
+    );
+    assert_eq!(
+        document.functions[1].arguments[0].default_value.as_deref(),
+        Some("\"/**/*.h\"")
+    );
+
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert!(document.functions.is_empty());
+
+    let content = "/**/\n/** Not doc. */\nvoid C();\n";
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert!(document.functions.is_empty());
+}
+
+#[test]
+fn test_trailing_doc_comments() {
+    let content = r#"
+enum class EFoo : uint8
+{
+    A = 1, ///< First.
+    B //!< Second.
+};
+
+struct FFoo
+{
+    float Speed; ///< Meters
+                 ///< per second.
+
+    /// Leading.
+    int32 Count; ///< Trailing.
+
+    void Bar(int32 X, ///< Horizontal.
+             int32 Y  ///< Vertical.
+    ); ///< Method.
+};
+
+/// Foo.
+UCLASS()
+class UFoo : public UObject
+{
+    GENERATED_BODY() //!< Body.
+
+public:
+    /// Baz.
+    void Baz();
+}; //!< Done.
+
+/// Function.
+void Bar();
+"#;
+    let settings = Settings {
+        show_all: true,
+        ..Default::default()
+    };
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let variants = &document.enums[0].variants;
+    assert_eq!(variants[0].doc_comments.as_deref(), Some("First."));
+    assert_eq!(variants[1].doc_comments.as_deref(), Some("Second."));
+    let item = &document.structs[0];
+    assert_eq!(
+        item.properties[0].doc_comments.as_deref(),
+        Some("Meters\nper second.")
+    );
+    assert_eq!(
+        item.properties[1].doc_comments.as_deref(),
+        Some("Leading.\n\nTrailing.")
+    );
+    let method = &item.methods[0];
+    assert_eq!(method.doc_comments.as_deref(), Some("Method."));
+    assert_eq!(
+        method.arguments[0].doc_comments.as_deref(),
+        Some("Horizontal.")
+    );
+    assert_eq!(
+        method.arguments[1].doc_comments.as_deref(),
+        Some("Vertical.")
+    );
+    let item = &document.classes[0];
+    assert_eq!(item.doc_comments.as_deref(), Some("Foo."));
+    assert_eq!(item.methods[0].name, "Baz");
+    assert_eq!(document.functions[0].name, "Bar");
+}
+
+#[test]
+fn test_namespaces() {
+    let content = r#"
+namespace A::B
+{
+    /// Function.
+    void Utils();
+
+    namespace C
+    {
+        /// Function.
+        void Utils();
+    }
+
+    namespace
+    {
+        /// Hidden.
+        void Hidden();
+    }
+}
+
+namespace
+{
+    /// Function.
+    void Utils();
+}
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let names = document
+        .functions
+        .iter()
+        .map(|item| item.full_name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["A::B::Utils", "A::B::C::Utils"]);
+}
+
+#[test]
+fn test_recovery() {
+    let content = r#"
+/// Before.
+void A();
+
+static auto Lambda = [](int32 Value) { return Value; };
+
+namespace N
+{
+    /// Inside.
+    void B();
+
+    static auto Lambda = [](int32 Value) { return Value; };
+}
+
+/// Struct.
+USTRUCT(BlueprintType)
+struct FFoo
+{
+    /// Property.
+    int32 Value;
+
+    TFunction<int32(int32)> Lambda = [](int32 Value) { return Value; };
+
+    /// Method.
+    void C();
+};
+
+/// Interface.
+UINTERFACE()
+class UBar : public UInterface
+{
+    GENERATED_BODY()
+
+    auto Lambda = []() {};
+};
+
+namespace
+{
+    /// Internal.
+    void D();
+
+    static auto Lambda = [](int32 Value) { return Value; };
+}
+
+namespace M
+{
+    static auto Lambda = []() { return 1'000; };
+
+    /// After digit separator.
+    void E();
+}
+
+/// Unclosed.
+struct FUnclosed
+{
+    /// Method.
+    void F();
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    let lines = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.line)
+        .collect::<Vec<_>>();
+    assert_eq!(lines, vec![5, 12, 22, 34, 47, 55]);
+    assert_eq!(diagnostics[5].message, "Unclosed block: missing `}`");
+    let names = document
+        .functions
+        .iter()
+        .map(|item| item.full_name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["A", "N::B", "M::E"]);
+    assert_eq!(document.structs.len(), 2);
+    let item = &document.structs[0];
+    assert_eq!(item.doc_comments.as_deref(), Some("Struct."));
+    assert!(matches!(
+        item.specifiers.as_ref().unwrap().attributes.as_slice(),
+        [Attribute::Single(name)] if name == "BlueprintType"
+    ));
+    assert_eq!(item.properties.len(), 1);
+    assert_eq!(item.methods.len(), 1);
+    assert!(document.classes.is_empty());
+    assert_eq!(document.interfaces.len(), 1);
+    assert_eq!(
+        document.interfaces[0].doc_comments.as_deref(),
+        Some("Interface.")
+    );
+    let item = &document.structs[1];
+    assert_eq!(item.name, "FUnclosed");
+    assert_eq!(item.methods.len(), 1);
+}
+
+#[test]
+fn test_nested_types() {
+    let content = r#"
+namespace Game
+{
+    /// Inventory.
+    ///
+    /// [`struct: Self::FSlot`]()
+    struct FInventory
+    {
+        /// Slot kind.
+        enum class EKind : uint8
+        {
+            Item,
+            Empty
+        };
+
+        /// Called when slot changes.
+        DECLARE_DYNAMIC_MULTICAST_DELEGATE_OneParam(FOnChanged, int32, Index);
+
+        /// Slot.
+        struct FSlot
+        {
+            /// Slot state.
+            class FState
+            {
+            };
+
+            /// Kind.
+            EKind Kind;
+        };
+
+    private:
+        /// Hidden.
+        struct FHidden
+        {
+        };
+    };
+}
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    document.resolve_nested_types();
+    document.resolve_self_names_in_docs();
+    let names = document
+        .enums
+        .iter()
+        .map(|item| item.full_name())
+        .chain(document.structs.iter().map(|item| item.full_name()))
+        .chain(document.classes.iter().map(|item| item.full_name()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            "Game::FInventory::EKind",
+            "Game::FInventory",
+            "Game::FInventory::FSlot",
+            "Game::FInventory::FSlot::FState",
+        ]
+    );
+    assert_eq!(
+        document.delegates[0].full_name(),
+        "Game::FInventory::FOnChanged"
+    );
+    assert_eq!(
+        document.classes[0].owner.as_deref(),
+        Some("FInventory::FSlot")
+    );
+    assert_eq!(
+        document.structs[0].doc_comments.as_deref(),
+        Some("Inventory.\n\n[`struct: FInventory::FSlot`]()")
+    );
+}
+
+#[test]
+fn test_function_qualifiers() {
+    let content = r#"
+/// Class.
+class FFoo
+{
+public:
+    /// A.
+    explicit FFoo(int32 Value) noexcept;
+    /// B.
+    virtual ~FFoo() = default;
+    /// C.
+    FFoo(const FFoo&) = delete;
+    /// D.
+    [[nodiscard]] FORCEINLINE constexpr int32 GetValue() const& noexcept(true);
+    /// E.
+    virtual void Tick(float DeltaTime) = 0;
+    /// F.
+    UE_NODISCARD inline auto Make() && -> TSharedPtr<FFoo> final;
+    /// G.
+    void Run() override final;
+};
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let item = &document.classes[0];
+    assert!(item.is_abstract);
+    let signatures = item
+        .methods
+        .iter()
+        .map(|item| {
+            item.signature()
+                .lines()
+                .skip(1)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        vec![
+            "explicit FFoo(\n    int32 Value\n) noexcept;",
+            "virtual ~FFoo() = default;",
+            "FFoo(\n    const FFoo&\n) = delete;",
+            "[[nodiscard]] FORCEINLINE constexpr int32 GetValue() const & noexcept(true);",
+            "virtual void Tick(\n    float DeltaTime\n) = 0;",
+            "UE_NODISCARD inline auto Make() && -> TSharedPtr<FFoo> final;",
+            "void Run() override final;",
+        ]
+    );
+}
+
+#[test]
+fn test_property_declarators() {
+    let content = r#"
+/// Struct.
+struct FFoo
+{
+    /// Flag.
+    UPROPERTY(EditAnywhere)
+    uint8 bIsActive : 1;
+    /// Lock.
+    mutable FCriticalSection Lock;
+    /// Coordinates.
+    int32 X, Y = 2; ///< In tiles.
+    /// Offset.
+    FVector Offset{0, 0, 1};
+};
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let properties = &document.structs[0].properties;
+    let signatures = properties
+        .iter()
+        .map(|item| {
+            item.signature()
+                .lines()
+                .skip(1)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        vec![
+            "uint8 bIsActive : 1;",
+            "mutable FCriticalSection Lock;",
+            "int32 X;",
+            "int32 Y;",
+            "FVector Offset;",
+        ]
+    );
+    assert!(properties[0].is_bool_flag());
+    assert!(properties[0].specifiers.is_some());
+    assert_eq!(
+        properties[2].doc_comments.as_deref(),
+        Some("Coordinates.\n\nIn tiles.")
+    );
+    assert_eq!(properties[2].doc_comments, properties[3].doc_comments);
+    assert_eq!(properties[3].default_value.as_deref(), Some("2"));
+    assert_eq!(properties[4].default_value.as_deref(), Some("{0, 0, 1}"));
+}
+
+#[test]
+fn test_deprecation() {
+    let content = r#"
+/// Class.
+class UE_DEPRECATED(5.0, "Use FBar instead.") MYGAME_API FFoo
+{
+public:
+    /// Function.
+    UE_DEPRECATED(5.1, "Use Bar instead." " Really.")
+    void Foo();
+
+    /// Property.
+    UPROPERTY(meta = (DeprecatedProperty, DeprecationMessage = "Not used anymore."))
+    int32 Value;
+
+    /// Function.
+    UFUNCTION(meta = (DeprecatedFunction))
+    void Bar();
+
+    /// Property.
+    [[deprecated("Use Value.")]] int32 OldValue;
+};
+
+/// Enum.
+enum class UE_DEPRECATED(5.2, "Gone.") EFoo : uint8 { A };
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let item = &document.classes[0];
+    assert_eq!(item.api.as_deref(), Some("MYGAME_API"));
+    let deprecated = item.deprecated.as_ref().unwrap();
+    assert_eq!(deprecated.version.as_deref(), Some("5.0"));
+    assert_eq!(deprecated.message.as_deref(), Some("Use FBar instead."));
+    let deprecated = item.methods[0].deprecated.as_ref().unwrap();
+    assert_eq!(
+        deprecated.message.as_deref(),
+        Some("Use Bar instead. Really.")
+    );
+    let deprecated = item.properties[0].deprecated.as_ref().unwrap();
+    assert_eq!(deprecated.version, None);
+    assert_eq!(deprecated.message.as_deref(), Some("Not used anymore."));
+    assert!(item.methods[1].deprecated.is_some());
+    let deprecated = item.properties[1].deprecated.as_ref().unwrap();
+    assert_eq!(deprecated.message.as_deref(), Some("Use Value."));
+    let deprecated = document.enums[0].deprecated.as_ref().unwrap();
+    assert_eq!(deprecated.version.as_deref(), Some("5.2"));
+}
+
+#[test]
+fn test_type_aliases() {
+    let content = r#"
+using namespace UE::Math;
+
+/// Item identifier.
+using FItemId = TTuple<int32, FName>;
+
+/// Array of items.
+template <typename T>
+using TItems = TArray<T>;
+
+/// Legacy identifier.
+typedef TMap<FName, int32> FLegacyIds;
+
+/// Inventory.
+struct FInventory
+{
+    using Super = FBase;
+
+    /// Slot index.
+    using FSlotIndex = int32;
+};
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    document.resolve_nested_types();
+    let signatures = document
+        .type_aliases
+        .iter()
+        .map(|item| (item.full_name(), item.signature()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        vec![
+            (
+                "FItemId".to_owned(),
+                "using FItemId = TTuple<int32, FName>;".to_owned()
+            ),
+            (
+                "TItems".to_owned(),
+                "template <typename T>\nusing TItems = TArray<T>;".to_owned()
+            ),
+            (
+                "FLegacyIds".to_owned(),
+                "typedef TMap<FName, int32> FLegacyIds;".to_owned()
+            ),
+            (
+                "FInventory::FSlotIndex".to_owned(),
+                "using FSlotIndex = int32;".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_preprocessor_conditions() {
+    let content = r#"
+#ifndef FOO_H
+#define FOO_H
+
+/// Foo.
+USTRUCT()
+struct FFoo
+{
+    GENERATED_BODY()
+
+    /// Value.
+    int32 Value = 0;
+
+#if WITH_EDITORONLY_DATA
+    /// Editor value.
+    int32 EditorValue = 0;
+#endif
+
+#if !UE_BUILD_SHIPPING
+    /// Prints debug info.
+    void Debug() const;
+#elif CUSTOM_BUILD
+    /// Custom.
+    void Custom() const;
+#else
+    /// Shipping.
+    void Shipping() const;
+#endif
+};
+
+#endif
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let item = &document.structs[0];
+    assert_eq!(item.condition, None);
+    assert_eq!(item.properties[0].condition, None);
+    let condition = item.properties[1].condition.as_deref().unwrap();
+    assert_eq!(condition, "WITH_EDITORONLY_DATA");
+    assert!(preprocessor::is_editor_only(condition));
+    let conditions = item
+        .methods
+        .iter()
+        .map(|item| item.condition.as_deref().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        conditions,
+        vec![
+            "!UE_BUILD_SHIPPING",
+            "UE_BUILD_SHIPPING && CUSTOM_BUILD",
+            "UE_BUILD_SHIPPING && !CUSTOM_BUILD",
+        ]
+    );
+    assert!(preprocessor::is_non_shipping(conditions[0]));
+    assert!(!preprocessor::is_non_shipping(conditions[1]));
+
+    let settings = Settings {
+        defines: Some([("UE_BUILD_SHIPPING".to_owned(), 1)].into()),
+        ..Default::default()
+    };
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let item = &document.structs[0];
+    assert_eq!(item.properties.len(), 1);
+    assert_eq!(item.methods.len(), 1);
+    assert_eq!(item.methods[0].name, "Shipping");
+}
+
+#[test]
+fn test_variables() {
+    let content = r#"
+namespace MyGame
+{
+    /// Default socket name.
+    extern MYGAME_API const FName DefaultSocket;
+
+    /// Maximal number of players.
+    static constexpr int32 MaxPlayers = 4;
+
+    /// Current frame.
+    inline thread_local uint64 Frame{0};
+
+    /// Names.
+    inline const TCHAR* Names[] = { TEXT("a"), TEXT("b") };
+}
+
+/// Identifier.
+FGUID Id;
+
+int32 Undocumented = 0;
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let signatures = document
+        .variables
+        .iter()
+        .map(|item| (item.full_name(), item.is_constant(), item.signature()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        signatures,
+        vec![
+            (
+                "MyGame::DefaultSocket".to_owned(),
+                true,
+                "extern MYGAME_API const FName DefaultSocket;".to_owned()
+            ),
+            (
+                "MyGame::MaxPlayers".to_owned(),
+                true,
+                "static constexpr int32 MaxPlayers = 4;".to_owned()
+            ),
+            (
+                "MyGame::Frame".to_owned(),
+                false,
+                "inline thread_local uint64 Frame{0};".to_owned()
+            ),
+            (
+                "MyGame::Names".to_owned(),
+                true,
+                "inline const TCHAR* Names[] = { TEXT(\"a\"), TEXT(\"b\") };".to_owned()
+            ),
+            ("Id".to_owned(), false, "FGUID Id;".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_templates() {
+    let content = r#"
+/// Traits.
+template <
+    /// Described type.
+    typename T,
+    int32 Size = 4, ///< Number of elements.
+    class... TArgs>
+struct TMyTraits
+{
+};
+
+/// Traits of vectors.
+template<>
+struct TMyTraits<FVector>
+{
+};
+
+/// Traits of arrays.
+template <typename T>
+struct TMyTraits<TArray<T>, 8>
+{
+};
+
+/// Container.
+template <template <typename> class TContainer, typename T = int32>
+class TWrapper
+{
+};
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let primary = &document.structs[0];
+    let parameters = &primary.template.as_ref().unwrap().parameters;
+    assert_eq!(parameters.len(), 3);
+    assert_eq!(parameters[0].kind, TemplateParameterKind::Type);
+    assert_eq!(parameters[0].name.as_deref(), Some("T"));
+    assert_eq!(
+        parameters[0].doc_comments.as_deref(),
+        Some("Described type.")
+    );
+    assert_eq!(parameters[1].kind, TemplateParameterKind::NonType);
+    assert_eq!(parameters[1].value_type, "int32");
+    assert_eq!(parameters[1].default_value.as_deref(), Some("4"));
+    assert_eq!(
+        parameters[1].doc_comments.as_deref(),
+        Some("Number of elements.")
+    );
+    assert_eq!(parameters[2].kind, TemplateParameterKind::Pack);
+    assert_eq!(parameters[2].signature(), "class... TArgs");
+    let specialization = &document.structs[1];
+    assert_eq!(specialization.full_name(), "TMyTraits<FVector>");
+    assert!(specialization
+        .template
+        .as_ref()
+        .unwrap()
+        .parameters
+        .is_empty());
+    assert_eq!(
+        specialization.signature(),
+        "template <>\nstruct TMyTraits<FVector>;"
+    );
+    assert!(primary.is_primary_template_of(specialization));
+    let specialization = &document.structs[2];
+    assert_eq!(specialization.full_name(), "TMyTraits<TArray<T>, 8>");
+    assert!(primary.is_primary_template_of(specialization));
+    assert_eq!(
+        document.classes[0].template.as_ref().unwrap().signature(),
+        "template <template <typename> class TContainer, typename T = int32>"
+    );
+}
+
+#[test]
+fn test_source_locations() {
+    let content = r#"
+/// Foo.
+struct FFoo
+{
+    /// Value.
+    int32 Value = 0;
+
+    /// Bar.
+    void Bar(
+        int32 A,
+        int32 B);
+};
+
+/// Broken.
+struct FBroken
+{
+    auto Lambda = []() {};
+
+    /// Value.
+    int32 Value = 0;
+};
+"#;
+    let mut document = Document::default();
+    parse_unreal_cpp_header("Public/Foo.h", content, &mut document, &Default::default());
+    let location = |file: &str, start_line, end_line| {
+        Some(SourceLocation {
+            file: file.to_owned(),
+            start_line,
+            end_line,
+        })
+    };
+    let item = &document.structs[0];
+    assert_eq!(item.location, location("Public/Foo.h", 3, 12));
+    assert_eq!(item.properties[0].location, location("Public/Foo.h", 6, 6));
+    assert_eq!(item.methods[0].location, location("Public/Foo.h", 9, 11));
+    let item = &document.structs[1];
+    assert_eq!(item.location, location("Public/Foo.h", 15, 21));
+    assert_eq!(
+        item.properties[0].location,
+        location("Public/Foo.h", 20, 20)
+    );
+}
+
+#[test]
+fn test_source_snippets() {
+    let content = r#"
+#include "Foo.h"
+
+void AFoo::BeginPlay()
+{
+    Super::BeginPlay();
+    //// [snippet: begin_play]
+    auto Lambda = [this]() { Spawn(); };
+    Lambda();
+    //// [/snippet]
+}
+
+/// Spawns actor.
+//// [proxy: spawning]
+//// void Spawn();
+//// [/proxy]
+"#;
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_source(
+        "Private/Foo.cpp",
+        content,
+        &mut document,
+        &Default::default(),
+    );
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert_eq!(
+        document.snippets.get("begin_play").map(|v| v.as_str()),
+        Some("auto Lambda = [this]() { Spawn(); };\nLambda();")
+    );
+    assert_eq!(document.proxy_functions.len(), 1);
+    assert_eq!(document.proxy_functions[0].item.name, "Spawn");
+}
+
+#[test]
+fn test_expressions() {
+    let content = r#"
+/// Foo.
+struct FFoo
+{
+    /// Flags.
+    int32 Flags = 1 << 3 | 0b1010'0101;
+    /// Lowest.
+    float Lowest = -FLT_MAX;
+    /// Mask.
+    uint8 Mask = 0xFF;
+    /// Epsilon.
+    float Epsilon = 1.5e-3f;
+    /// Name.
+    FString Name = TEXT("x \"quoted\"");
+    /// Separator.
+    TCHAR Separator = '\n';
+    /// Mode.
+    int32 Mode = bEnabled ? 1 : 0;
+    /// Scale.
+    float Scale = (float)Count / static_cast<float>(Total);
+    /// Raw.
+    FString Raw = R"json({"a": 1})json";
+    /// Length.
+    int32 Length = sizeof(FVector*) * FMath::Max(A, B).Num() + Items[0];
+    /// Origin.
+    FVector Origin = { .X = 0.0, .Y = 1.0, };
+
+    /// Bar.
+    void Bar(bool bValue = !(A && B) || C >= 2, const TCHAR* Text = L"wide");
+};
+
+/// Values.
+enum class EValues : uint8
+{
+    A = 1 << 0,
+    B = 1 << 1,
+    C = A | B,
+};
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let defaults = document.structs[0]
+        .properties
+        .iter()
+        .map(|item| item.default_value.as_deref().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        defaults,
+        vec![
+            "1 << 3 | 0b1010'0101",
+            "-FLT_MAX",
+            "0xFF",
+            "1.5e-3f",
+            "TEXT(\"x \\\"quoted\\\"\")",
+            "'\\n'",
+            "bEnabled ? 1 : 0",
+            "(float)Count / static_cast<float>(Total)",
+            "R\"json({\"a\": 1})json\"",
+            "sizeof(FVector*) * FMath::Max(A, B).Num() + Items[0]",
+            "{ .X = 0.0, .Y = 1.0, }",
+        ]
+    );
+    let arguments = &document.structs[0].methods[0].arguments;
+    assert_eq!(
+        arguments[0].default_value.as_deref(),
+        Some("!(A && B) || C >= 2")
+    );
+    assert_eq!(arguments[1].default_value.as_deref(), Some("L\"wide\""));
+    let values = document.enums[0]
+        .variants
+        .iter()
+        .map(|item| item.value.as_deref().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec!["1 << 0", "1 << 1", "A | B"]);
+}
+
+#[test]
+fn test_macros() {
+    let content = r#"
+#include "Foo.generated.h"
+
+#define MYGAME_API_DEPRECATED(Version, Message) MYGAME_API
+
+/// Old foo.
+struct MYGAME_API_DEPRECATED(5.1,
+    "Use FBar instead.") FFoo
+{
+    /// Cached value.
+    FORCEINLINE_DEBUGGABLE static int32 Value = STRINGIFY(Cached);
+};
+
+/// Not exported.
+struct NOT_EXPORTED FBaz
+{
+};
+
+/// Exported.
+class MYGAME_API UBar : public UObject
+{
+    GENERATED_BODY()
+};
+
+/// Short.
+struct FS
+{
+    /// Member.
+    int32 M;
+};
+
+/// Single letter.
+class A
+{
+};
+
+/// All caps.
+class UA : public UObject
+{
+    GENERATED_BODY()
+};
+
+/// Makes short.
+FS MAKE_FS ();
+
+UE_INLINE_GENERATED_CPP_BY_NAME(Foo)
+"#;
+    let settings = Settings {
+        api_macros: vec!["MYGAME_API".to_owned()],
+        macros: [
+            (
+                "MYGAME_API_DEPRECATED(Version, ...)".to_owned(),
+                "MYGAME_API UE_DEPRECATED(Version, __VA_ARGS__)".to_owned(),
+            ),
+            ("FORCEINLINE_DEBUGGABLE".to_owned(), "inline".to_owned()),
+            ("STRINGIFY(Value)".to_owned(), "TEXT(#Value)".to_owned()),
+            (
+                "UE_INLINE_GENERATED_CPP_BY_NAME(Name)".to_owned(),
+                "".to_owned(),
+            ),
+        ]
+        .into(),
+        ..Default::default()
+    };
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let item = &document.structs[0];
+    assert_eq!(item.name, "FFoo");
+    assert_eq!(item.api.as_deref(), Some("MYGAME_API"));
+    let deprecated = item.deprecated.as_ref().unwrap();
+    assert_eq!(deprecated.version.as_deref(), Some("5.1"));
+    assert_eq!(deprecated.message.as_deref(), Some("Use FBar instead."));
+    assert_eq!(item.location.as_ref().unwrap().start_line, 7);
+    let property = &item.properties[0];
+    assert!(property.is_inline);
+    assert_eq!(property.default_value.as_deref(), Some("TEXT(\"Cached\")"));
+    assert_eq!(property.location.as_ref().unwrap().start_line, 11);
+    assert_eq!(document.structs[1].name, "FBaz");
+    assert_eq!(document.structs[1].api, None);
+    assert_eq!(document.classes[0].name, "UBar");
+    assert_eq!(document.classes[0].api.as_deref(), Some("MYGAME_API"));
+    assert_eq!(document.structs[2].name, "FS");
+    assert_eq!(document.structs[2].properties[0].name, "M");
+    assert_eq!(document.classes[1].name, "A");
+    assert_eq!(document.classes[2].name, "UA");
+    assert_eq!(document.functions[0].name, "MAKE_FS");
+    assert_eq!(document.functions[0].return_type.as_deref(), Some("FS"));
+}
+
+#[test]
+fn test_argument_specifiers() {
+    let content = r#"
+/// Test library.
+UCLASS()
+class UTestLibrary : public UBlueprintFunctionLibrary
+{
+    GENERATED_BODY()
+
+public:
+    /// Collects items.
+    UFUNCTION(BlueprintCallable)
+    static void Collect(
+        UPARAM(ref) TArray<int32>& Items,
+        UPARAM(DisplayName = "Target Actor") AActor* InActor,
+        TArray<int32>& OutItems,
+        const FVector& Location,
+        UPARAM(meta = (DisplayName = "Fallback")) FName Name = NAME_None);
+};
+"#;
+    let mut document = Document::default();
+    let diagnostics =
+        parse_unreal_cpp_header("Test.h", content, &mut document, &Default::default());
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let arguments = document.classes[0].methods[0]
+        .arguments
+        .iter()
+        .map(|item| (item.is_ref(), item.is_output(), item.display_name()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        arguments,
+        vec![
+            (true, false, None),
+            (false, false, Some("Target Actor")),
+            (false, true, None),
+            (false, false, None),
+            (false, false, Some("Fallback")),
+        ]
+    );
+}
+
+#[test]
+fn test_directives() {
+    let content = r#"
+/// Character movement.
+//// [alias: UMoveComponent]
+//// [group: Movement]
+UCLASS()
+class UMovementComponent : public UActorComponent
+{
+    GENERATED_BODY()
+
+public:
+    /// Maximal speed.
+    //// [group: Speed]
+    //// [alias: Speed]
+    UPROPERTY()
+    float MaxSpeed = 0.0f;
+
+    /// Internal state.
+    //// [hidden]
+    UPROPERTY()
+    int32 State = 0;
+
+    //// [hidden]
+    void Tick();
+};
+
+//// [hidden]
+struct FInternal
+{
+};
+
+/// Mode.
+enum class EMode
+{
+    /// Walking.
+    Walking,
+    //// [hidden]
+    Internal, ///< Internal.
+    MAX
+};
+
+/// Moves.
+void Move(
+    //// [hidden]
+    float Speed);
+"#;
+    let settings = Settings {
+        show_all: true,
+        ..Default::default()
+    };
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    assert!(document.structs.is_empty());
+    let item = &document.classes[0];
+    assert_eq!(item.doc_comments.as_deref(), Some("Character movement."));
+    assert_eq!(item.directives.group.as_deref(), Some("Movement"));
+    assert_eq!(item.directives.aliases, vec!["UMoveComponent".to_owned()]);
+    assert!(item.methods.is_empty());
+    assert_eq!(item.properties.len(), 1);
+    let property = &item.properties[0];
+    assert_eq!(property.doc_comments.as_deref(), Some("Maximal speed."));
+    assert_eq!(property.directives.group.as_deref(), Some("Speed"));
+    assert!(property.directives.has_alias(None, None, "Speed"));
+    let item = &document.enums[0];
+    assert_eq!(
+        item.signature(),
+        "enum class EMode {\n    Walking,\n    MAX\n};"
+    );
+    assert_eq!(item.variants[0].doc_comments.as_deref(), Some("Walking."));
+    assert_eq!(item.variants[1].doc_comments, None);
+    assert_eq!(document.functions[0].arguments[0].doc_comments, None);
+}
+
+#[test]
+fn test_injects() {
+    let content = r#"
+/// Injected method.
+//// [proxy: methods]
+//// void Injected() const;
+//// [/proxy]
+
+/// Generated handle.
+//// [proxy: types]
+//// struct FHandle
+//// {
+//// 	/// Handle value.
+//// 	int32 Value = 0;
+//// };
+//// [/proxy]
+
+/// Generated state.
+//// [proxy: types]
+//// enum class EState : uint8 { Idle, Busy };
+//// [/proxy]
+
+/// Generated delegate.
+//// [proxy:]
+//// DECLARE_DELEGATE_OneParam(FOnGenerated, int32, Value);
+//// [/proxy]
+
+namespace Game
+{
+    /// Generated settings.
+    //// [proxy:]
+    //// struct FGeneratedSettings {};
+    //// [/proxy]
+}
+
+/// Class with injects.
+UCLASS()
+class UFoo : public UObject
+{
+    GENERATED_BODY()
+
+    //// [inject: methods, types]
+    GENERATE_ALL()
+
+public:
+    /// Nested type with injects.
+    struct FNested
+    {
+        //// [inject: methods, unknown]
+        GENERATE_METHODS()
+    };
+};
+
+UINTERFACE()
+class UBar : public UInterface
+{
+    GENERATED_BODY()
+};
+
+/// Interface with injects.
+class IBar
+{
+    GENERATED_BODY()
+
+    //// [inject: methods]
+    GENERATE_METHODS()
+};
+"#;
+    let settings = Settings::default();
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    document.resolve_nested_types();
+    document.resolve_interfaces(&settings);
+    let diagnostics = document.resolve_injects();
+    assert_eq!(diagnostics.len(), 1);
+    let (file, diagnostic) = &diagnostics[0];
+    assert_eq!(file, "Test.h");
+    assert_eq!(diagnostic.line, 45);
+    assert_eq!(
+        diagnostic.message,
+        "Unmatched inject tag `unknown` in: UFoo::FNested"
+    );
+    document.sort_items_by_name();
+    assert_eq!(document.classes[0].name, "UFoo");
+    assert_eq!(document.classes[0].methods[0].name, "Injected");
+    assert_eq!(document.interfaces[0].methods[0].name, "Injected");
+    let names = document
+        .structs
+        .iter()
+        .map(|item| item.full_name())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["UFoo::FHandle", "UFoo::FNested", "Game::FGeneratedSettings"]
+    );
+    assert_eq!(document.structs[0].properties[0].name, "Value");
+    assert_eq!(document.structs[1].methods[0].name, "Injected");
+    assert_eq!(document.enums[0].full_name(), "UFoo::EState");
+    assert_eq!(document.delegates[0].full_name(), "FOnGenerated");
+}
+
+#[test]
+fn test_macro_injects() {
+    let content = r#"
+/// Serializes item.
+//// [proxy: SERIALIZABLE]
+//// bool Serialize(FArchive& Archive);
+//// [/proxy]
+
+/// Gets $Name$ property.
+//// [proxy: PROPERTY_GETTER(Type, Name)]
+//// $Type$ Get$Name$() const;
+//// [/proxy]
+
+/// Item with generated members.
+USTRUCT()
+struct FItem
+{
+    GENERATED_BODY()
+    SERIALIZABLE()
+    PROPERTY_GETTER(int32, Count);
+    PROPERTY_GETTER(FName, Id);
+};
+
+/// Other item with generated members.
+USTRUCT()
+struct FOtherItem
+{
+    GENERATED_BODY()
+    PROPERTY_GETTER(int32, Count);
+};
+"#;
+    let settings = Settings::default();
+    let mut document = Document::default();
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    let diagnostics = resolve_macro_injects(&mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    document.resolve_injects();
+    document.sort_items_by_name();
+    let item = &document.structs[0];
+    assert_eq!(item.name, "FItem");
+    let names = item
+        .methods
+        .iter()
+        .map(|item| item.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["GetCount", "GetId", "Serialize"]);
+    assert_eq!(item.methods[0].return_type.as_deref(), Some("int32"));
+    assert_eq!(item.methods[1].return_type.as_deref(), Some("FName"));
+    assert_eq!(
+        item.methods[1].doc_comments.as_deref(),
+        Some("Gets Id property.")
+    );
+    let item = &document.structs[1];
+    assert_eq!(item.name, "FOtherItem");
+    assert_eq!(item.methods.len(), 1);
+    assert_eq!(item.methods[0].name, "GetCount");
+}
+
+#[test]
+fn test_doc_tags() {
+    let content = r#"
+/**
+ * Finds item in storage.
+ *
+ * @param Storage - Storage to search.
+ * @param[in] Id: Identifier of item,
+ *            that is unique.
+ * @tparam T Type of item.
+ * @return Found item, or null.
+ * @see FItemStorage, UInventory::Add
+ * @note Search is linear.
+ * @warning Not thread safe.
+ * @since 5.1
+ *
+ * @code
+ * Find<FItem>(Storage, 42);
+ * @endcode
+ */
+template <typename T>
+T* Find(
+    const FItemStorage& Storage,
+    /// Item id.
+    int32 Id);
+
+/// Old item.
+/// \deprecated Use FNewItem instead.
+struct FOldItem
+{
+};
+
+/// Kind of item.
+enum class EItemKind
+{
+    /**
+     * Consumable item.
+     * @since 5.2
+     * @see FItemStorage
+     */
+    Consumable,
+};
+"#;
+    let settings = Settings {
+        block_doc_comments: true,
+        ..Default::default()
+    };
     let mut document = Document::default();
-    parse_unreal_cpp_header(&content, &mut document, &Default::default())
-        .unwrap_or_else(|error| panic!("Error parsing C++ header: {}", error));
+    let diagnostics = parse_unreal_cpp_header("Test.h", content, &mut document, &settings);
+    assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    document.resolve_doc_tags();
+    let item = &document.functions[0];
+    assert_eq!(
+        item.doc_comments.as_deref(),
+        Some("Finds item in storage.\n\n```cpp\nFind<FItem>(Storage, 42);\n```")
+    );
+    assert_eq!(
+        item.arguments[0].doc_comments.as_deref(),
+        Some("Storage to search.")
+    );
+    assert_eq!(item.arguments[1].doc_comments.as_deref(), Some("Item id."));
+    assert_eq!(
+        item.doc_tags.parameters[1],
+        (
+            "Id".to_owned(),
+            "Identifier of item, that is unique.".to_owned()
+        )
+    );
+    assert_eq!(
+        item.template.as_ref().unwrap().parameters[0]
+            .doc_comments
+            .as_deref(),
+        Some("Type of item.")
+    );
+    assert_eq!(
+        item.doc_tags.returns.as_deref(),
+        Some("Found item, or null.")
+    );
+    assert_eq!(
+        item.doc_tags.see_also,
+        vec!["FItemStorage, UInventory::Add"]
+    );
+    assert_eq!(item.doc_tags.notes, vec!["Search is linear."]);
+    assert_eq!(item.doc_tags.warnings, vec!["Not thread safe."]);
+    assert_eq!(item.doc_tags.since.as_deref(), Some("5.1"));
+    let item = &document.structs[0];
+    assert_eq!(item.doc_comments.as_deref(), Some("Old item."));
+    assert_eq!(
+        item.deprecated.as_ref().unwrap().message.as_deref(),
+        Some("Use FNewItem instead.")
+    );
+    let variant = &document.enums[0].variants[0];
+    assert_eq!(variant.doc_comments.as_deref(), Some("Consumable item."));
+    assert_eq!(variant.doc_tags.since.as_deref(), Some("5.2"));
+    assert_eq!(variant.doc_tags.see_also, vec!["FItemStorage"]);
 }
@@ -1,9 +1,9 @@
-use crate::{config::*, document::*, ensure_dir, read_file};
+use crate::{ast::preprocessor, config::*, document::*, ensure_dir, read_file};
 use fs_extra::{copy_items, dir::CopyOptions};
 use regex::{Captures, Regex};
 use serde::Serialize;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, HashSet},
     fs::{remove_dir_all, write},
     path::Path,
     process::Command,
@@ -38,6 +38,8 @@ pub struct BookHtml {
     no_section_label: bool,
     site_url: String,
     fold: BookFold,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    redirect: BTreeMap<String, String>,
 }
 
 #[derive(Serialize)]
@@ -56,7 +58,7 @@ pub fn bake_mdbook(document: &Document, config: &Config, root: &Path) {
         let _ = remove_dir_all(&config.output_dir);
     }
 
-    write_manifest(config);
+    write_manifest(config, document);
 
     let mut files = HashMap::new();
     let mut index = "# Index\n\n".to_owned();
@@ -79,80 +81,191 @@ pub fn bake_mdbook(document: &Document, config: &Config, root: &Path) {
     let mut reference_listing = "# C++ API Reference\n".to_owned();
     documentation.push_str("- [C++ API Reference](/reference.md)\n");
 
-    if !document.enums.is_empty() {
-        index.push_str("  - [Enums](reference/enums.md)\n");
-        reference_listing.push_str("\n## Enums\n");
-        let mut listing = "# Enums\n\n".to_owned();
-        for item in &document.enums {
-            let index_path = format!("reference/enums/{}.md", item.name);
-            let file_path = format!("src/reference/enums/{}.md", item.name);
-            let mut content = String::default();
-            bake_enum(item, &mut content);
-            files.insert(file_path, content);
-            let entry = format!("    - [{}]({})\n", item.name, index_path);
-            index.push_str(&entry);
-            let entry = format!("- [`{}`]({})\n", item.name, index_path);
-            listing.push_str(&entry);
-            reference_listing.push_str(&entry);
-        }
-        files.insert("src/reference/enums.md".to_owned(), listing);
-    }
-
-    if !document.structs.is_empty() {
-        index.push_str("  - [Structs](reference/structs.md)\n");
-        reference_listing.push_str("\n## Structs\n");
-        let mut listing = "# Structs\n\n".to_owned();
-        for item in &document.structs {
-            let index_path = format!("reference/structs/{}.md", item.name);
-            let file_path = format!("src/reference/structs/{}.md", item.name);
-            let mut content = String::default();
-            bake_struct_class(item, &mut content);
-            files.insert(file_path, content);
-            let entry = format!("    - [{}]({})\n", item.name, index_path);
-            index.push_str(&entry);
-            let entry = format!("- [`{}`]({})\n", item.name, index_path);
-            listing.push_str(&entry);
-            reference_listing.push_str(&entry);
-        }
-        files.insert("src/reference/structs.md".to_owned(), listing);
-    }
-
-    if !document.classes.is_empty() {
-        index.push_str("  - [Classes](reference/classes.md)\n");
-        reference_listing.push_str("\n## Classes\n");
-        let mut listing = "# Classes\n\n".to_owned();
-        for item in &document.classes {
-            let index_path = format!("reference/classes/{}.md", item.name);
-            let file_path = format!("src/reference/classes/{}.md", item.name);
-            let mut content = String::default();
-            bake_struct_class(item, &mut content);
-            files.insert(file_path, content);
-            let entry = format!("    - [{}]({})\n", item.name, index_path);
-            index.push_str(&entry);
-            let entry = format!("- [`{}`]({})\n", item.name, index_path);
-            listing.push_str(&entry);
-            reference_listing.push_str(&entry);
-        }
-        files.insert("src/reference/classes.md".to_owned(), listing);
-    }
-
-    if !document.functions.is_empty() {
-        index.push_str("  - [Functions](reference/functions.md)\n");
-        reference_listing.push_str("\n## Functions\n");
-        let mut listing = "# Functions\n\n".to_owned();
-        for item in &document.functions {
-            let index_path = format!("reference/functions/{}.md", item.name);
-            let file_path = format!("src/reference/functions/{}.md", item.name);
-            let mut content = String::default();
-            bake_function(item, &mut content, false);
-            files.insert(file_path, content);
-            let entry = format!("    - [{}]({})\n", item.name, index_path);
-            index.push_str(&entry);
-            let entry = format!("- [`{}`]({})\n", item.name, index_path);
-            listing.push_str(&entry);
-            reference_listing.push_str(&entry);
-        }
-        files.insert("src/reference/functions.md".to_owned(), listing);
+    bake_reference_section(
+        "Enums",
+        "enums",
+        document
+            .enums
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_enum(item, document, &mut content);
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    item.scoped_name(),
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    bake_reference_section(
+        "Structs",
+        "structs",
+        document
+            .structs
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_struct_class(item, document, &mut content);
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    item.scoped_name(),
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    bake_reference_section(
+        "Classes",
+        "classes",
+        document
+            .classes
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_struct_class(item, document, &mut content);
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    item.scoped_name(),
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    bake_reference_section(
+        "Interfaces",
+        "interfaces",
+        document
+            .interfaces
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_interface(item, document, &mut content);
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    item.name.to_owned(),
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    bake_reference_section(
+        "Delegates",
+        "delegates",
+        document
+            .delegates
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_delegate(item, document, &mut content);
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    item.scoped_name(),
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    bake_reference_section(
+        "Type Aliases",
+        "aliases",
+        document
+            .type_aliases
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_type_alias(item, document, &mut content);
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    item.scoped_name(),
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    bake_reference_section(
+        "Functions",
+        "functions",
+        document
+            .functions
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_function(item, document, &mut content, false);
+                let name = specialized_name(&item.name, item.specialization.as_deref());
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    name,
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    bake_reference_section(
+        "Variables & Constants",
+        "variables",
+        document
+            .variables
+            .iter()
+            .map(|item| {
+                let mut content = String::default();
+                bake_variable(item, document, &mut content);
+                (
+                    item.namespace.to_owned(),
+                    item.directives.group.to_owned(),
+                    item.name.to_owned(),
+                    content,
+                )
+            })
+            .collect(),
+        &mut files,
+        &mut index,
+        &mut reference_listing,
+    );
+
+    let deprecated_listing = bake_deprecated_listing(document);
+    if !deprecated_listing.is_empty() {
+        index.push_str("\n- [Deprecated API](deprecated.md)\n");
+        documentation.push_str("- [Deprecated API](/deprecated.md)\n");
+        files.insert(
+            "src/deprecated.md".to_owned(),
+            format!("# Deprecated API\n{}", deprecated_listing),
+        );
     }
 
     files.insert("src/reference.md".to_owned(), reference_listing);
@@ -239,6 +352,208 @@ pub fn bake_mdbook(document: &Document, config: &Config, root: &Path) {
     }
 }
 
+/// Entries are tuples of: namespace, group, name and baked page content.
+fn bake_reference_section(
+    title: &str,
+    directory: &str,
+    entries: Vec<(Option<String>, Option<String>, String, String)>,
+    files: &mut HashMap<String, String>,
+    index: &mut String,
+    reference_listing: &mut String,
+) {
+    if entries.is_empty() {
+        return;
+    }
+    index.push_str(&format!("  - [{}](reference/{}.md)\n", title, directory));
+    for (group, entries) in grouped(&entries, |(_, group, _, _)| group.as_deref()) {
+        let indent = match group {
+            Some(group) => {
+                index.push_str(&format!("    - [{}]()\n", group));
+                "      "
+            }
+            None => "    ",
+        };
+        for (namespace, _, name, _) in entries {
+            let full_name = qualified_name(namespace.as_deref(), name);
+            index.push_str(&format!(
+                "{}- [{}](reference/{}/{}.md)\n",
+                indent,
+                full_name,
+                directory,
+                page_path(&full_name)
+            ));
+        }
+    }
+    reference_listing.push_str(&format!("\n## {}\n", title));
+    let mut listing = format!("# {}\n\n", title);
+    let mut last_namespace = None;
+    for (namespace, _, name, content) in entries {
+        if namespace.is_some() && namespace != last_namespace {
+            let header = namespace.as_deref().unwrap_or_default();
+            listing.push_str(&format!("\n## Namespace: `{}`\n\n", header));
+            reference_listing.push_str(&format!("\n### Namespace: `{}`\n", header));
+        }
+        let full_name = qualified_name(namespace.as_deref(), &name);
+        let index_path = format!("reference/{}/{}.md", directory, page_path(&full_name));
+        let file_path = format!("src/{}", index_path);
+        files.insert(file_path, content);
+        let entry = format!("- [`{}`]({})\n", name, index_path);
+        listing.push_str(&entry);
+        reference_listing.push_str(&entry);
+        last_namespace = namespace;
+    }
+    files.insert(format!("src/reference/{}.md", directory), listing);
+}
+
+/// Lists deprecated items and members grouped by item kind, or nothing if there are none.
+fn bake_deprecated_listing(document: &Document) -> String {
+    let mut result = String::new();
+    let mut section = |title: &str, entries: Vec<String>| {
+        if !entries.is_empty() {
+            result.push_str(&format!("\n## {}\n\n", title));
+            for entry in entries {
+                result.push_str(&entry);
+            }
+        }
+    };
+    section(
+        "Enums",
+        document
+            .enums
+            .iter()
+            .filter_map(|item| deprecated_entry("enum", &item.full_name(), &item.deprecated))
+            .collect(),
+    );
+    section(
+        "Structs",
+        document
+            .structs
+            .iter()
+            .flat_map(|item| deprecated_struct_class_entries("struct", item))
+            .collect(),
+    );
+    section(
+        "Classes",
+        document
+            .classes
+            .iter()
+            .flat_map(|item| deprecated_struct_class_entries("class", item))
+            .collect(),
+    );
+    section(
+        "Interfaces",
+        document
+            .interfaces
+            .iter()
+            .flat_map(|item| {
+                let name = item.full_name();
+                deprecated_entry("interface", &name, &item.deprecated)
+                    .into_iter()
+                    .chain(deprecated_member_entries(
+                        "interface",
+                        &name,
+                        &item.properties,
+                        &item.methods,
+                    ))
+                    .collect::<Vec<_>>()
+            })
+            .collect(),
+    );
+    section(
+        "Delegates",
+        document
+            .delegates
+            .iter()
+            .filter_map(|item| deprecated_entry("delegate", &item.full_name(), &item.deprecated))
+            .collect(),
+    );
+    section(
+        "Type Aliases",
+        document
+            .type_aliases
+            .iter()
+            .filter_map(|item| deprecated_entry("alias", &item.full_name(), &item.deprecated))
+            .collect(),
+    );
+    section(
+        "Functions",
+        document
+            .functions
+            .iter()
+            .filter_map(|item| deprecated_entry("function", &item.full_name(), &item.deprecated))
+            .collect(),
+    );
+    section(
+        "Variables & Constants",
+        document
+            .variables
+            .iter()
+            .filter_map(|item| deprecated_entry("variable", &item.full_name(), &item.deprecated))
+            .collect(),
+    );
+    result
+}
+
+fn deprecated_struct_class_entries(element: &str, item: &StructClass) -> Vec<String> {
+    let name = item.full_name();
+    deprecated_entry(element, &name, &item.deprecated)
+        .into_iter()
+        .chain(deprecated_member_entries(
+            element,
+            &name,
+            &item.properties,
+            &item.methods,
+        ))
+        .collect()
+}
+
+fn deprecated_member_entries(
+    element: &str,
+    owner: &str,
+    properties: &[Property],
+    methods: &[Function],
+) -> Vec<String> {
+    properties
+        .iter()
+        .map(|item| (&item.name, &item.deprecated))
+        .chain(methods.iter().map(|item| (&item.name, &item.deprecated)))
+        .filter_map(|(name, deprecated)| {
+            deprecated_entry(element, &format!("{}::{}", owner, name), deprecated)
+        })
+        .collect()
+}
+
+fn deprecated_entry(element: &str, name: &str, deprecated: &Option<Deprecation>) -> Option<String> {
+    deprecated.as_ref().map(|deprecated| {
+        let mut result = format!("- [`{}: {}`]()", element, name);
+        if let Some(version) = &deprecated.version {
+            result.push_str(&format!(" - since `{}`", version));
+        }
+        if let Some(message) = &deprecated.message {
+            result.push_str(&format!(" - {}", message));
+        }
+        result.push('\n');
+        result
+    })
+}
+
+/// Turns item name into page path, replacing characters of template specialization arguments
+/// that are not allowed in file names.
+fn page_path(full_name: &str) -> String {
+    full_name
+        .replace("::", "/")
+        .chars()
+        .filter(|c| *c != ' ')
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '/' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
 fn preprocess_content(
     content: &str,
     document: &Document,
@@ -258,33 +573,35 @@ fn preprocess_content(
 
 fn replace_code_references(content: &str, document: &Document) -> String {
     // TODO: put that regex in lazy static to not perform costly compilation on each call.
-    let re = Regex::new(r"\[`\s*(\w+)\s*:\s*(\w+)\s*(::\s*(\w+))?`\]s*\(\s*\)").unwrap();
+    let re = Regex::new(
+        r"\[`\s*(\w+)\s*:\s*(\w+(\s*<[^`]*>)?(\s*::\s*\w+(\s*<[^`]*>)?)*)\s*`\]s*\(\s*\)",
+    )
+    .unwrap();
     re.replace_all(content, |captures: &Captures| {
         let element = captures.get(1).unwrap().as_str().trim();
-        let name = captures.get(2).unwrap().as_str().trim();
-        let section = captures.get(4).map(|m| m.as_str().trim());
-        let path = match element {
-            "enum" => document
-                .enums
-                .iter()
-                .find(|item| item.name == name)
-                .map(|_| format!("/reference/enums/{}.md", name)),
-            "struct" => document
-                .structs
-                .iter()
-                .find(|item| item.name == name)
-                .map(|_| format!("/reference/structs/{}.md", name)),
-            "class" => document
-                .classes
-                .iter()
-                .find(|item| item.name == name)
-                .map(|_| format!("/reference/classes/{}.md", name)),
-            "function" => document
-                .functions
-                .iter()
-                .find(|item| item.name == name)
-                .map(|_| format!("/reference/functions/{}.md", name)),
-            _ => None,
+        let path = captures
+            .get(2)
+            .unwrap()
+            .as_str()
+            .split("::")
+            .map(|part| part.trim())
+            .collect::<Vec<_>>();
+        let (name, section, path) = match find_code_reference(document, element, &path.join("::")) {
+            Some((name, path)) => (name, None, Some(path)),
+            None if path.len() > 1 => {
+                let name = path[..(path.len() - 1)].join("::");
+                let section = path.last().copied();
+                match find_code_reference(document, element, &name) {
+                    Some((name, path)) => {
+                        let section = section.map(|section| {
+                            find_member_name(document, element, &name, section).unwrap_or(section)
+                        });
+                        (name, section, Some(path))
+                    }
+                    None => (name, section, None),
+                }
+            }
+            None => (path.join("::"), None, None),
         };
         if let Some(path) = path {
             if let Some(section) = section {
@@ -307,6 +624,199 @@ fn replace_code_references(content: &str, document: &Document) -> String {
     .into()
 }
 
+/// Finds referenced item either by its fully qualified or its short name and returns its
+/// fully qualified name along with path to its page.
+fn find_code_reference(document: &Document, element: &str, name: &str) -> Option<(String, String)> {
+    let (directory, full_name) = match element {
+        "enum" => (
+            "enums",
+            document
+                .enums
+                .iter()
+                .find(|item| {
+                    item.full_name() == name || item.scoped_name() == name || item.name == name
+                })
+                .or_else(|| {
+                    document.enums.iter().find(|item| {
+                        item.directives.has_alias(
+                            item.namespace.as_deref(),
+                            item.owner.as_deref(),
+                            name,
+                        )
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        "struct" => (
+            "structs",
+            document
+                .structs
+                .iter()
+                .find(|item| {
+                    item.full_name() == name || item.scoped_name() == name || item.name == name
+                })
+                .or_else(|| {
+                    document.structs.iter().find(|item| {
+                        item.directives.has_alias(
+                            item.namespace.as_deref(),
+                            item.owner.as_deref(),
+                            name,
+                        )
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        "class" => (
+            "classes",
+            document
+                .classes
+                .iter()
+                .find(|item| {
+                    item.full_name() == name || item.scoped_name() == name || item.name == name
+                })
+                .or_else(|| {
+                    document.classes.iter().find(|item| {
+                        item.directives.has_alias(
+                            item.namespace.as_deref(),
+                            item.owner.as_deref(),
+                            name,
+                        )
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        "interface" => (
+            "interfaces",
+            document
+                .interfaces
+                .iter()
+                .find(|item| {
+                    item.full_name() == name
+                        || item.name == name
+                        || item.native_name() == name
+                        || item.reflection_name() == name
+                })
+                .or_else(|| {
+                    document.interfaces.iter().find(|item| {
+                        item.directives
+                            .has_alias(item.namespace.as_deref(), None, name)
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        "delegate" => (
+            "delegates",
+            document
+                .delegates
+                .iter()
+                .find(|item| {
+                    item.full_name() == name || item.scoped_name() == name || item.name == name
+                })
+                .or_else(|| {
+                    document.delegates.iter().find(|item| {
+                        item.directives.has_alias(
+                            item.namespace.as_deref(),
+                            item.owner.as_deref(),
+                            name,
+                        )
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        "alias" => (
+            "aliases",
+            document
+                .type_aliases
+                .iter()
+                .find(|item| {
+                    item.full_name() == name || item.scoped_name() == name || item.name == name
+                })
+                .or_else(|| {
+                    document.type_aliases.iter().find(|item| {
+                        item.directives.has_alias(
+                            item.namespace.as_deref(),
+                            item.owner.as_deref(),
+                            name,
+                        )
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        "function" => (
+            "functions",
+            document
+                .functions
+                .iter()
+                .find(|item| item.full_name() == name || item.name == name)
+                .or_else(|| {
+                    document.functions.iter().find(|item| {
+                        item.directives
+                            .has_alias(item.namespace.as_deref(), None, name)
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        "variable" => (
+            "variables",
+            document
+                .variables
+                .iter()
+                .find(|item| item.full_name() == name || item.name == name)
+                .or_else(|| {
+                    document.variables.iter().find(|item| {
+                        item.directives
+                            .has_alias(item.namespace.as_deref(), None, name)
+                    })
+                })
+                .map(|item| item.full_name()),
+        ),
+        _ => return None,
+    };
+    full_name.map(|full_name| {
+        let path = format!("/reference/{}/{}.md", directory, page_path(&full_name));
+        (full_name, path)
+    })
+}
+
+/// Finds current name of member of referenced item, if given name is one of its aliases.
+fn find_member_name<'a>(
+    document: &'a Document,
+    element: &str,
+    full_name: &str,
+    name: &str,
+) -> Option<&'a str> {
+    let (properties, methods) = match element {
+        "struct" | "class" => {
+            let items = if element == "struct" {
+                &document.structs
+            } else {
+                &document.classes
+            };
+            let item = items.iter().find(|item| item.full_name() == full_name)?;
+            (&item.properties, &item.methods)
+        }
+        "interface" => {
+            let item = document
+                .interfaces
+                .iter()
+                .find(|item| item.full_name() == full_name)?;
+            (&item.properties, &item.methods)
+        }
+        _ => return None,
+    };
+    let has_alias = |directives: &Directives| directives.aliases.iter().any(|alias| alias == name);
+    properties
+        .iter()
+        .find(|item| has_alias(&item.directives))
+        .map(|item| item.name.as_str())
+        .or_else(|| {
+            methods
+                .iter()
+                .find(|item| has_alias(&item.directives))
+                .map(|item| item.name.as_str())
+        })
+}
+
 fn replace_snippets(content: &str, document: &Document) -> String {
     // TODO: put that regex in lazy static to not perform costly compilation on each call.
     let re = Regex::new(r"```\s*snippet[\n\r]+([\s/]*)(\w+)[\r\n]+\s*```").unwrap();
@@ -423,9 +933,36 @@ fn bake_specifiers(specifiers: &Specifiers, content: &mut String) {
     content.push('\n');
 }
 
-fn bake_enum(item: &Enum, content: &mut String) {
-    content.push_str(&format!("# **Enum: `{}`**\n\n", item.name));
+fn bake_deprecation(deprecated: &Option<Deprecation>, content: &mut String) {
+    if let Some(deprecated) = deprecated {
+        content.push_str("> **Deprecated**");
+        if let Some(version) = &deprecated.version {
+            content.push_str(&format!(" since `{}`", version));
+        }
+        if let Some(message) = &deprecated.message {
+            content.push_str(&format!(": {}", message));
+        }
+        content.push_str("\n\n");
+    }
+}
+
+fn bake_condition(condition: &Option<String>, content: &mut String) {
+    if let Some(condition) = condition {
+        if preprocessor::is_editor_only(condition) {
+            content.push_str("**_Editor only_**\n\n");
+        }
+        if preprocessor::is_non_shipping(condition) {
+            content.push_str("**_Non-shipping_**\n\n");
+        }
+        content.push_str(&format!("**Condition:** `{}`\n\n", condition));
+    }
+}
+
+fn bake_enum(item: &Enum, document: &Document, content: &mut String) {
+    content.push_str(&format!("# **Enum: `{}`**\n\n", item.full_name()));
     content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+    bake_deprecation(&item.deprecated, content);
+    bake_condition(&item.condition, content);
     if let Some(specifiers) = &item.specifiers {
         content.push_str("---\n\n");
         bake_specifiers(specifiers, content);
@@ -433,38 +970,230 @@ fn bake_enum(item: &Enum, content: &mut String) {
     content.push_str("---\n\n");
     content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
     content.push_str("\n\n");
+    bake_doc_tags(&item.doc_tags, document, content);
+    if !item.variants.is_empty() {
+        content.push_str("---\n\n# **Variants**\n\n");
+        content.push_str("| Name | Value | Display Name | Description |\n");
+        content.push_str("| --- | --- | --- | --- |\n");
+        for variant in &item.variants {
+            content.push_str(&format!(
+                "| <span id=\"{}\">`{}`</span> | {} | {} | {} |\n",
+                variant.name.to_lowercase(),
+                variant.name,
+                variant
+                    .value
+                    .as_ref()
+                    .map(|value| format!("`{}`", table_cell(value)))
+                    .unwrap_or_default(),
+                variant.display_name().map(table_cell).unwrap_or_default(),
+                variant_description(variant, document),
+            ));
+        }
+        content.push_str("\n\n");
+    }
 }
 
-fn bake_struct_class(item: &StructClass, content: &mut String) {
+/// Puts doc comments of enum variant in table cell, followed by its Doxygen tags.
+fn variant_description(variant: &EnumVariant, document: &Document) -> String {
+    let tags = &variant.doc_tags;
+    let mut lines = variant
+        .doc_comments
+        .iter()
+        .filter(|content| !content.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    if let Some(deprecated) = &tags.deprecated {
+        lines.push(format!("**Deprecated:** {}", deprecated));
+    }
+    if let Some(since) = &tags.since {
+        lines.push(format!("**Since:** `{}`", since));
+    }
+    lines.extend(tags.notes.iter().cloned());
+    for warning in &tags.warnings {
+        lines.push(format!("**Warning:** {}", warning));
+    }
+    for entry in &tags.see_also {
+        lines.push(format!("**See also:** {}", see_also_entry(document, entry)));
+    }
+    table_cell(&lines.join("\n"))
+}
+
+fn table_cell(content: &str) -> String {
+    content
+        .trim()
+        .replace('|', "\\|")
+        .lines()
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+fn bake_struct_class(item: &StructClass, document: &Document, content: &mut String) {
     match item.mode {
-        StructClassMode::Struct => content.push_str(&format!("# **Struct: `{}`**\n\n", item.name)),
-        StructClassMode::Class => content.push_str(&format!("# **Class: `{}`**\n\n", item.name)),
+        StructClassMode::Struct => {
+            content.push_str(&format!("# **Struct: `{}`**\n\n", item.full_name()))
+        }
+        StructClassMode::Class => {
+            content.push_str(&format!("# **Class: `{}`**\n\n", item.full_name()))
+        }
     }
     content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+    bake_deprecation(&item.deprecated, content);
+    bake_condition(&item.condition, content);
+    if item.is_abstract {
+        content.push_str("**_Abstract_**\n\n");
+    }
     if let Some(specifiers) = &item.specifiers {
         content.push_str("---\n\n");
         bake_specifiers(specifiers, content);
     }
+    let element = match item.mode {
+        StructClassMode::Struct => "struct",
+        StructClassMode::Class => "class",
+    };
+    let items = match item.mode {
+        StructClassMode::Struct => &document.structs,
+        StructClassMode::Class => &document.classes,
+    };
+    if let Some(primary) = items
+        .iter()
+        .find(|primary| primary.is_primary_template_of(item))
+    {
+        content.push_str(&format!(
+            "**Specialization of:** [`{}: {}`]()\n\n",
+            element,
+            primary.full_name()
+        ));
+    }
     content.push_str("---\n\n");
     content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
     content.push_str("\n\n");
-    if !item.properties.is_empty() {
+    bake_template_parameters(&item.template, content);
+    bake_doc_tags(&item.doc_tags, document, content);
+    bake_specializations(
+        element,
+        items
+            .iter()
+            .filter(|other| item.is_primary_template_of(other))
+            .map(|other| other.full_name()),
+        content,
+    );
+    bake_nested_types(&item.namespace, &item.scoped_name(), document, content);
+    bake_members(&item.properties, &item.methods, document, content);
+}
+
+/// Lists links to enums, structs, classes, type aliases and delegates declared inside given owner
+/// type.
+fn bake_nested_types(
+    namespace: &Option<String>,
+    owner: &str,
+    document: &Document,
+    content: &mut String,
+) {
+    let owner = Some(owner);
+    let mut entries = vec![];
+    for item in &document.enums {
+        if &item.namespace == namespace && item.owner.as_deref() == owner {
+            entries.push(format!("- [`enum: {}`]()\n", item.full_name()));
+        }
+    }
+    for item in &document.structs {
+        if &item.namespace == namespace && item.owner.as_deref() == owner {
+            entries.push(format!("- [`struct: {}`]()\n", item.full_name()));
+        }
+    }
+    for item in &document.classes {
+        if &item.namespace == namespace && item.owner.as_deref() == owner {
+            entries.push(format!("- [`class: {}`]()\n", item.full_name()));
+        }
+    }
+    for item in &document.type_aliases {
+        if &item.namespace == namespace && item.owner.as_deref() == owner {
+            entries.push(format!("- [`alias: {}`]()\n", item.full_name()));
+        }
+    }
+    for item in &document.delegates {
+        if &item.namespace == namespace && item.owner.as_deref() == owner {
+            entries.push(format!("- [`delegate: {}`]()\n", item.full_name()));
+        }
+    }
+    if !entries.is_empty() {
+        content.push_str("---\n\n# **Nested Types**\n\n");
+        for entry in entries {
+            content.push_str(&entry);
+        }
+        content.push_str("\n\n");
+    }
+}
+
+fn bake_interface(item: &Interface, document: &Document, content: &mut String) {
+    content.push_str(&format!("# **Interface: `{}`**\n\n", item.full_name()));
+    content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+    bake_deprecation(&item.deprecated, content);
+    bake_condition(&item.condition, content);
+    if let Some(specifiers) = &item.specifiers {
+        content.push_str("---\n\n");
+        bake_specifiers(specifiers, content);
+    }
+    content.push_str("---\n\n");
+    content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
+    content.push_str("\n\n");
+    bake_doc_tags(&item.doc_tags, document, content);
+    bake_nested_types(&item.namespace, &item.native_name(), document, content);
+    bake_members(&item.properties, &item.methods, document, content);
+}
+
+/// Bakes properties and methods sections, each listing ungrouped members before groups.
+fn bake_members(
+    properties: &[Property],
+    methods: &[Function],
+    document: &Document,
+    content: &mut String,
+) {
+    if !properties.is_empty() {
         content.push_str("---\n\n# **Properties**\n\n");
-        for property in &item.properties {
-            bake_property(property, content, true);
+        for (group, properties) in grouped(properties, |item| item.directives.group.as_deref()) {
+            if let Some(group) = group {
+                content.push_str(&format!("## _{}_\n\n", group));
+            }
+            for property in properties {
+                bake_property(property, document, content, true);
+            }
         }
         content.push_str("\n\n");
     }
-    if !item.methods.is_empty() {
+    if !methods.is_empty() {
         content.push_str("---\n\n# **Methods**\n\n");
-        for method in &item.methods {
-            bake_function(method, content, true);
+        for (group, methods) in grouped(methods, |item| item.directives.group.as_deref()) {
+            if let Some(group) = group {
+                content.push_str(&format!("## _{}_\n\n", group));
+            }
+            for method in methods {
+                bake_function(method, document, content, true);
+            }
         }
         content.push_str("\n\n");
     }
 }
 
-fn bake_property(item: &Property, content: &mut String, member: bool) {
+/// Splits items into ungrouped ones followed by groups sorted by name, keeping order of items.
+fn grouped<'a, T>(
+    items: &'a [T],
+    group: impl Fn(&'a T) -> Option<&'a str>,
+) -> Vec<(Option<&'a str>, Vec<&'a T>)> {
+    let mut result = vec![(None, vec![])];
+    for item in items {
+        let name = group(item);
+        match result.iter_mut().find(|(group, _)| *group == name) {
+            Some((_, items)) => items.push(item),
+            None => result.push((name, vec![item])),
+        }
+    }
+    result[1..].sort_by_key(|(group, _)| *group);
+    result.retain(|(_, items)| !items.is_empty());
+    result
+}
+
+fn bake_property(item: &Property, document: &Document, content: &mut String, member: bool) {
     let level = if member {
         content.push_str(&format!("* # __`{}`__\n\n", item.name));
         4
@@ -475,6 +1204,17 @@ fn bake_property(item: &Property, content: &mut String, member: bool) {
     let indented = indent(level, &{
         let mut content = String::default();
         content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+        bake_deprecation(&item.deprecated, &mut content);
+        bake_condition(&item.condition, &mut content);
+        if item.is_bool_flag() {
+            content.push_str("**Type:** `bool`\n\n");
+        }
+        if let Some(delegate) = find_delegate(document, &item.value_type) {
+            content.push_str(&format!(
+                "**Delegate:** [`delegate: {}`]()\n\n",
+                delegate.full_name()
+            ));
+        }
         if let Some(specifiers) = &item.specifiers {
             content.push_str("---\n\n");
             bake_specifiers(specifiers, &mut content);
@@ -482,23 +1222,115 @@ fn bake_property(item: &Property, content: &mut String, member: bool) {
         content.push_str("---\n\n");
         content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
         content.push_str("\n\n");
+        bake_doc_tags(&item.doc_tags, document, &mut content);
         content
     });
     content.push_str(&indented);
     content.push_str("\n\n");
 }
 
-fn bake_function(item: &Function, content: &mut String, member: bool) {
+fn bake_delegate(item: &Delegate, document: &Document, content: &mut String) {
+    content.push_str(&format!("# **Delegate: `{}`**\n\n", item.full_name()));
+    content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+    bake_deprecation(&item.deprecated, content);
+    bake_condition(&item.condition, content);
+    content.push_str("---\n\n");
+    if item.is_dynamic {
+        content.push_str("**_Dynamic_**\n\n");
+    }
+    if item.is_multicast {
+        content.push_str("**_Multicast_**\n\n");
+    }
+    if item.is_sparse {
+        content.push_str("**_Sparse_**\n\n");
+    }
+    if let Some(return_type) = &item.return_type {
+        content.push_str(&format!("**Returns:** `{}`\n\n", return_type));
+    }
+    content.push_str("---\n\n");
+    content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
+    content.push_str("\n\n");
+    if !item.arguments.is_empty() {
+        content.push_str("---\n\n# **Arguments**\n\n");
+        for argument in &item.arguments {
+            bake_function_argument(argument, content);
+        }
+        content.push_str("\n\n");
+    }
+    bake_doc_tags(&item.doc_tags, document, content);
+}
+
+fn bake_type_alias(item: &TypeAlias, document: &Document, content: &mut String) {
+    content.push_str(&format!("# **Type Alias: `{}`**\n\n", item.full_name()));
+    content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+    bake_deprecation(&item.deprecated, content);
+    bake_condition(&item.condition, content);
+    content.push_str("---\n\n");
+    content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
+    content.push_str("\n\n");
+    bake_template_parameters(&item.template, content);
+    bake_doc_tags(&item.doc_tags, document, content);
+}
+
+fn bake_variable(item: &Variable, document: &Document, content: &mut String) {
+    let kind = if item.is_constant() {
+        "Constant"
+    } else {
+        "Variable"
+    };
+    content.push_str(&format!("# **{}: `{}`**\n\n", kind, item.full_name()));
+    content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+    bake_deprecation(&item.deprecated, content);
+    bake_condition(&item.condition, content);
+    content.push_str("---\n\n");
+    content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
+    content.push_str("\n\n");
+    bake_doc_tags(&item.doc_tags, document, content);
+}
+
+/// Finds documented delegate used as property value type.
+fn find_delegate<'a>(document: &'a Document, value_type: &str) -> Option<&'a Delegate> {
+    let value_type = value_type.strip_prefix("const ").unwrap_or(value_type);
+    let pat: &[_] = &['*', '&', ' '];
+    let value_type = value_type.trim_end_matches(pat);
+    document.delegates.iter().find(|item| {
+        item.full_name() == value_type
+            || item.scoped_name() == value_type
+            || item.name == value_type
+    })
+}
+
+fn bake_function(item: &Function, document: &Document, content: &mut String, member: bool) {
     let level = if member {
         content.push_str(&format!("* # __`{}`__\n\n", item.name));
         4
     } else {
-        content.push_str(&format!("# **Function: `{}`**\n\n", item.name));
+        content.push_str(&format!("# **Function: `{}`**\n\n", item.full_name()));
         0
     };
     let indented = indent(level, &{
         let mut content = String::default();
         content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+        bake_deprecation(&item.deprecated, &mut content);
+        bake_condition(&item.condition, &mut content);
+        if item.is_pure_virtual {
+            content.push_str("**_Pure virtual_**\n\n");
+        }
+        if item.is_deleted {
+            content.push_str("**_Deleted_**\n\n");
+        }
+        if !member {
+            if let Some(primary) = document
+                .functions
+                .iter()
+                .find(|primary| primary.is_primary_template_of(item))
+            {
+                content.push_str(&format!(
+                    "**Specialization of:** [`function: {}`]()\n\n",
+                    primary.full_name()
+                ));
+            }
+        }
         if member {
             content.push_str("<details>\n\n");
         }
@@ -509,6 +1341,7 @@ fn bake_function(item: &Function, content: &mut String, member: bool) {
         content.push_str("---\n\n");
         content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
         content.push_str("\n\n");
+        bake_template_parameters(&item.template, &mut content);
         if !item.arguments.is_empty() {
             content.push_str("---\n\n# **Arguments**\n\n");
             for argument in &item.arguments {
@@ -516,6 +1349,18 @@ fn bake_function(item: &Function, content: &mut String, member: bool) {
             }
             content.push_str("\n\n");
         }
+        bake_doc_tags(&item.doc_tags, document, &mut content);
+        if !member {
+            bake_specializations(
+                "function",
+                document
+                    .functions
+                    .iter()
+                    .filter(|other| item.is_primary_template_of(other))
+                    .map(|other| other.full_name()),
+                &mut content,
+            );
+        }
         if member {
             content.push_str("</details>\n\n");
         }
@@ -525,6 +1370,123 @@ fn bake_function(item: &Function, content: &mut String, member: bool) {
     content.push_str("\n\n");
 }
 
+/// Bakes returns, notes and see also sections out of Doxygen tags.
+fn bake_doc_tags(tags: &DocTags, document: &Document, content: &mut String) {
+    if let Some(returns) = &tags.returns {
+        content.push_str("---\n\n# **Returns**\n\n");
+        content.push_str(returns);
+        content.push_str("\n\n");
+    }
+    if tags.since.is_some() || !tags.notes.is_empty() || !tags.warnings.is_empty() {
+        content.push_str("---\n\n# **Notes**\n\n");
+        if let Some(since) = &tags.since {
+            content.push_str(&format!("- **Since:** `{}`\n", since));
+        }
+        for note in &tags.notes {
+            content.push_str(&format!("- {}\n", note));
+        }
+        for warning in &tags.warnings {
+            content.push_str(&format!("- **Warning:** {}\n", warning));
+        }
+        content.push_str("\n\n");
+    }
+    if !tags.see_also.is_empty() {
+        content.push_str("---\n\n# **See also**\n\n");
+        for entry in &tags.see_also {
+            content.push_str(&format!("- {}\n", see_also_entry(document, entry)));
+        }
+        content.push_str("\n\n");
+    }
+}
+
+/// Turns comma separated names of items or their members into code references, leaving any
+/// other content as it is.
+fn see_also_entry(document: &Document, entry: &str) -> String {
+    const ELEMENTS: &[&str] = &[
+        "class",
+        "struct",
+        "interface",
+        "enum",
+        "delegate",
+        "alias",
+        "function",
+        "variable",
+    ];
+    let names = entry
+        .split(',')
+        .map(|name| name.trim().trim_end_matches("()"))
+        .collect::<Vec<_>>();
+    let is_path = |name: &&str| {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == ':')
+    };
+    if !names.iter().all(is_path) {
+        return entry.to_owned();
+    }
+    names
+        .into_iter()
+        .map(|name| {
+            let owner = name.rsplit_once("::").map(|(owner, _)| owner);
+            ELEMENTS
+                .iter()
+                .find(|element| {
+                    find_code_reference(document, element, name).is_some()
+                        || owner
+                            .map(|owner| find_code_reference(document, element, owner).is_some())
+                            .unwrap_or_default()
+                })
+                .map(|element| format!("[`{}: {}`]()", element, name))
+                .unwrap_or_else(|| format!("`{}`", name))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn bake_template_parameters(template: &Option<Template>, content: &mut String) {
+    let parameters = match template {
+        Some(template) if !template.parameters.is_empty() => &template.parameters,
+        _ => return,
+    };
+    content.push_str("---\n\n# **Template Parameters**\n\n");
+    for item in parameters {
+        if let Some(name) = &item.name {
+            content.push_str(&format!("* ## __`{}`__\n\n", name));
+        } else {
+            content.push_str("* _Unnamed_\n\n");
+        }
+        let indented = indent(4, &{
+            let mut content = String::default();
+            content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+            content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
+            content.push_str("\n\n");
+            content
+        });
+        content.push_str(&indented);
+        content.push_str("\n\n");
+    }
+    content.push_str("\n\n");
+}
+
+/// Lists links to specializations of primary template.
+fn bake_specializations(
+    element: &str,
+    full_names: impl Iterator<Item = String>,
+    content: &mut String,
+) {
+    let entries = full_names
+        .map(|full_name| format!("- [`{}: {}`]()\n", element, full_name))
+        .collect::<Vec<_>>();
+    if !entries.is_empty() {
+        content.push_str("---\n\n# **Specializations**\n\n");
+        for entry in entries {
+            content.push_str(&entry);
+        }
+        content.push_str("\n\n");
+    }
+}
+
 fn bake_function_argument(item: &Argument, content: &mut String) {
     if let Some(name) = &item.name {
         content.push_str(&format!("* ## __`{}`__\n\n", name));
@@ -534,6 +1496,14 @@ fn bake_function_argument(item: &Argument, content: &mut String) {
     let indented = indent(4, &{
         let mut content = String::default();
         content.push_str(&format!("```cpp\n{}\n```\n\n", item.signature()));
+        if let Some(display_name) = item.display_name() {
+            content.push_str(&format!("**Display name:** `{}`\n\n", display_name));
+        }
+        if item.is_ref() {
+            content.push_str("**_By reference_**\n\n");
+        } else if item.is_output() {
+            content.push_str("**_Output_**\n\n");
+        }
         content.push_str(&item.doc_comments.to_owned().unwrap_or_default());
         content.push_str("\n\n");
         content
@@ -554,8 +1524,9 @@ fn indent(level: usize, content: &str) -> String {
     }
 }
 
-fn write_manifest(config: &Config) {
+fn write_manifest(config: &Config, document: &Document) {
     let mdbook = config.backend_mdbook.as_ref().cloned().unwrap_or_default();
+    let site_url = mdbook.site_url.unwrap_or("/".to_string());
     let manifest = Book {
         book: BookInner {
             authors: mdbook.authors.to_owned(),
@@ -570,7 +1541,8 @@ fn write_manifest(config: &Config) {
                 preferred_dark_theme: "ayu".to_owned(),
                 mathjax_support: true,
                 no_section_label: true,
-                site_url: mdbook.site_url.unwrap_or("/".to_string()),
+                redirect: bake_redirects(document, &site_url),
+                site_url,
                 fold: BookFold {
                     enable: false,
                     level: 0,
@@ -584,3 +1556,81 @@ fn write_manifest(config: &Config) {
     write(&path, content)
         .unwrap_or_else(|_| panic!("Could not write mdbook manifest file: {:?}", path));
 }
+
+/// Maps pages of former item names to their current pages, skipping names taken by other items.
+fn bake_redirects(document: &Document, site_url: &str) -> BTreeMap<String, String> {
+    let mut entries = vec![];
+    for item in &document.enums {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), item.owner.as_deref());
+        entries.push(("enums", item.full_name(), aliases));
+    }
+    for item in &document.structs {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), item.owner.as_deref());
+        entries.push(("structs", item.full_name(), aliases));
+    }
+    for item in &document.classes {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), item.owner.as_deref());
+        entries.push(("classes", item.full_name(), aliases));
+    }
+    for item in &document.interfaces {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), None);
+        entries.push(("interfaces", item.full_name(), aliases));
+    }
+    for item in &document.delegates {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), item.owner.as_deref());
+        entries.push(("delegates", item.full_name(), aliases));
+    }
+    for item in &document.type_aliases {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), item.owner.as_deref());
+        entries.push(("aliases", item.full_name(), aliases));
+    }
+    for item in &document.functions {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), None)
+            .into_iter()
+            .map(|alias| specialized_name(&alias, item.specialization.as_deref()))
+            .collect();
+        entries.push(("functions", item.full_name(), aliases));
+    }
+    for item in &document.variables {
+        let aliases = item
+            .directives
+            .alias_full_names(item.namespace.as_deref(), None);
+        entries.push(("variables", item.full_name(), aliases));
+    }
+    let pages = entries
+        .iter()
+        .map(|(directory, full_name, _)| format!("{}/{}", directory, page_path(full_name)))
+        .collect::<HashSet<_>>();
+    let mut result = BTreeMap::new();
+    for (directory, full_name, aliases) in &entries {
+        for alias in aliases {
+            let page = format!("{}/{}", directory, page_path(alias));
+            if !pages.contains(&page) {
+                result.insert(
+                    format!("/reference/{}.html", page),
+                    format!(
+                        "{}/reference/{}/{}.html",
+                        site_url.trim_end_matches('/'),
+                        directory,
+                        page_path(full_name)
+                    ),
+                );
+            }
+        }
+    }
+    result
+}
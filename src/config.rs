@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Backend {
@@ -59,11 +59,47 @@ impl BackendMdBook {
     }
 }
 
+/// File extensions of input files per role they play.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputExtensions {
+    /// Headers parsed for API.
+    #[serde(default = "InputExtensions::default_headers")]
+    pub headers: Vec<String>,
+    /// Source files scanned only for snippets and proxies.
+    #[serde(default)]
+    pub sources: Vec<String>,
+    /// Book pages.
+    #[serde(default = "InputExtensions::default_book")]
+    pub book: Vec<String>,
+}
+
+impl Default for InputExtensions {
+    fn default() -> Self {
+        Self {
+            headers: Self::default_headers(),
+            sources: vec![],
+            book: Self::default_book(),
+        }
+    }
+}
+
+impl InputExtensions {
+    fn default_headers() -> Vec<String> {
+        vec!["h".to_owned()]
+    }
+
+    fn default_book() -> Vec<String> {
+        vec!["md".to_owned()]
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default)]
     pub dependencies: Vec<PathBuf>,
     pub input_dirs: Vec<PathBuf>,
+    #[serde(default)]
+    pub input_extensions: InputExtensions,
     pub output_dir: PathBuf,
     #[serde(default)]
     pub backend: Backend,
@@ -80,4 +116,21 @@ pub struct Settings {
     pub document_protected: bool,
     #[serde(default)]
     pub document_private: bool,
+    #[serde(default)]
+    pub block_doc_comments: bool,
+    /// Preprocessor defines used to drop items compiled out of the build.
+    #[serde(default)]
+    pub defines: Option<HashMap<String, i64>>,
+    /// Known API export macros. When set, only these get documented as API of items.
+    #[serde(default)]
+    pub api_macros: Vec<String>,
+    /// Macros expanded before parsing, keyed by either name or function-like signature.
+    #[serde(default)]
+    pub macros: HashMap<String, String>,
+}
+
+impl Settings {
+    pub fn is_api_macro(&self, name: &str) -> bool {
+        self.api_macros.is_empty() || self.api_macros.iter().any(|item| item == name)
+    }
 }
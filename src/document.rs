@@ -1,12 +1,356 @@
-use crate::config::Settings;
+use crate::{ast::unreal_cpp_header::Diagnostic, config::Settings};
+use regex::{Captures, Regex};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 pub type Type = String;
-pub type Template = String;
 
 fn replace_self_names(content: &str, owner: &str) -> String {
-    content.replace("$Self$", owner)
+    // TODO: put that regex in lazy static to not perform costly compilation on each call.
+    let re = Regex::new(r"(\[`\s*\w+\s*:\s*)Self\b").unwrap();
+    let content = content.replace("$Self$", owner);
+    re.replace_all(&content, |captures: &Captures| {
+        format!("{}{}", captures.get(1).unwrap().as_str(), owner)
+    })
+    .into()
+}
+
+pub fn qualified_name(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{}::{}", namespace, name),
+        None => name.to_owned(),
+    }
+}
+
+/// Place in header file item got declared in.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// Path relative to input directory.
+    pub file: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+/// Per-item directives put in doc comments with `//// [...]` lines.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Directives {
+    /// Excludes item from documentation, even with `show_all` setting.
+    #[serde(default)]
+    pub hidden: bool,
+    /// Group item gets listed under.
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Former names of item.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl Directives {
+    /// Fully qualified former names of item, where aliases not qualified already get qualified
+    /// with namespace and owner of item.
+    pub fn alias_full_names(&self, namespace: Option<&str>, owner: Option<&str>) -> Vec<String> {
+        self.aliases
+            .iter()
+            .map(|alias| {
+                if alias.contains("::") {
+                    alias.to_owned()
+                } else {
+                    qualified_name(namespace, &qualified_name(owner, alias))
+                }
+            })
+            .collect()
+    }
+
+    /// Tells if item was formerly named with given either short or fully qualified name.
+    pub fn has_alias(&self, namespace: Option<&str>, owner: Option<&str>, name: &str) -> bool {
+        self.aliases.iter().any(|alias| alias == name)
+            || self
+                .alias_full_names(namespace, owner)
+                .iter()
+                .any(|alias| alias == name)
+    }
+
+    pub fn merge(&mut self, other: Directives) {
+        self.hidden |= other.hidden;
+        if self.group.is_none() {
+            self.group = other.group;
+        }
+        self.aliases.extend(other.aliases);
+    }
+}
+
+/// Doxygen style tags taken out of doc comments, like `@return` or `\see`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocTags {
+    /// Argument names with their descriptions, provided with `@param`.
+    #[serde(default)]
+    pub parameters: Vec<(String, String)>,
+    /// Template parameter names with their descriptions, provided with `@tparam`.
+    #[serde(default)]
+    pub template_parameters: Vec<(String, String)>,
+    #[serde(default)]
+    pub returns: Option<String>,
+    #[serde(default)]
+    pub see_also: Vec<String>,
+    #[serde(default)]
+    pub notes: Vec<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+    /// Version item got introduced in.
+    #[serde(default)]
+    pub since: Option<String>,
+    /// Deprecation message, also applied to item deprecation.
+    #[serde(default)]
+    pub deprecated: Option<String>,
+}
+
+impl DocTags {
+    /// Takes tags out of doc comments, returning remaining content, where `@code` blocks become
+    /// code blocks. Tag lasts until either next tag or empty line.
+    pub fn extract(content: &str) -> (String, Self) {
+        let mut result = Self::default();
+        let mut lines = vec![];
+        let mut tag = None;
+        let mut code = false;
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if code {
+                if matches!(doc_tag(trimmed), Some(("endcode", _))) {
+                    lines.push("```");
+                    code = false;
+                } else {
+                    lines.push(line);
+                }
+                continue;
+            }
+            match doc_tag(trimmed) {
+                Some(("code", _)) => {
+                    result.add(tag.take());
+                    lines.push("```cpp");
+                    code = true;
+                }
+                Some((name, text)) => {
+                    result.add(tag.take());
+                    tag = Some((name, text.to_owned()));
+                }
+                None if trimmed.is_empty() => {
+                    result.add(tag.take());
+                    // Skip empty lines left in place of tags.
+                    if !lines
+                        .last()
+                        .map(|line| line.trim().is_empty())
+                        .unwrap_or(true)
+                    {
+                        lines.push(line);
+                    }
+                }
+                None => match &mut tag {
+                    Some((_, text)) => {
+                        if !text.is_empty() {
+                            text.push(' ');
+                        }
+                        text.push_str(trimmed);
+                    }
+                    None => lines.push(line),
+                },
+            }
+        }
+        result.add(tag);
+        if code {
+            lines.push("```");
+        }
+        (lines.join("\n").trim().to_owned(), result)
+    }
+
+    fn add(&mut self, tag: Option<(&str, String)>) {
+        let (name, text) = match tag {
+            Some((name, text)) => (name, text.trim().to_owned()),
+            None => return,
+        };
+        // Name can be followed by `-` or `:` separator, as in `@param InActor - The actor`.
+        let named = || {
+            let (name, text) = text.split_once(char::is_whitespace).unwrap_or((&text, ""));
+            let text = text.trim_start();
+            let text = text
+                .strip_prefix(['-', ':'])
+                .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+                .unwrap_or(text);
+            (
+                name.trim_end_matches(':').to_owned(),
+                text.trim().to_owned(),
+            )
+        };
+        match name {
+            _ if text.is_empty() => {}
+            "param" => self.parameters.push(named()),
+            "tparam" => self.template_parameters.push(named()),
+            "return" | "returns" => self.returns = Some(text),
+            "see" | "sa" => self.see_also.push(text),
+            "note" => self.notes.push(text),
+            "warning" => self.warnings.push(text),
+            "since" => self.since = Some(text),
+            "deprecated" => self.deprecated = Some(text),
+            _ => {}
+        }
+    }
+
+    /// Describes arguments that have no doc comments of their own.
+    pub fn apply_to_arguments(&self, arguments: &mut [Argument]) {
+        for (name, text) in &self.parameters {
+            if let Some(argument) = arguments
+                .iter_mut()
+                .find(|argument| argument.name.as_ref() == Some(name))
+            {
+                if argument.doc_comments.is_none() {
+                    argument.doc_comments = Some(text.to_owned());
+                }
+            }
+        }
+    }
+
+    /// Describes template parameters that have no doc comments of their own.
+    pub fn apply_to_template(&self, template: &mut Option<Template>) {
+        let parameters = match template {
+            Some(template) => &mut template.parameters,
+            None => return,
+        };
+        for (name, text) in &self.template_parameters {
+            if let Some(parameter) = parameters
+                .iter_mut()
+                .find(|parameter| parameter.name.as_ref() == Some(name))
+            {
+                if parameter.doc_comments.is_none() {
+                    parameter.doc_comments = Some(text.to_owned());
+                }
+            }
+        }
+    }
+}
+
+/// Returns name and content of Doxygen tag line starts with, ignoring `@param` direction.
+fn doc_tag(line: &str) -> Option<(&'static str, &str)> {
+    const NAMES: &[&str] = &[
+        "param",
+        "tparam",
+        "returns",
+        "return",
+        "see",
+        "sa",
+        "note",
+        "warning",
+        "since",
+        "deprecated",
+        "code",
+        "endcode",
+    ];
+    let line = line.strip_prefix('@').or_else(|| line.strip_prefix('\\'))?;
+    let name = NAMES.iter().find(|name| {
+        line.strip_prefix(**name)
+            .map(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+            .unwrap_or_default()
+    })?;
+    let mut rest = &line[name.len()..];
+    if rest.starts_with('[') {
+        rest = rest
+            .split_once(']')
+            .map(|(_, rest)| rest)
+            .unwrap_or_default();
+    } else if rest.starts_with('{') {
+        rest = rest
+            .split_once('}')
+            .map(|(_, rest)| rest)
+            .unwrap_or_default();
+    }
+    Some((name, rest.trim()))
+}
+
+/// Takes Doxygen tags out of item doc comments and applies deprecation provided with them.
+fn resolve_doc_tags(
+    doc_comments: &mut Option<String>,
+    deprecated: &mut Option<Deprecation>,
+) -> DocTags {
+    let content = match doc_comments {
+        Some(content) => content,
+        None => return DocTags::default(),
+    };
+    let (text, tags) = DocTags::extract(content);
+    *content = text;
+    if let Some(message) = &tags.deprecated {
+        let deprecated = deprecated.get_or_insert_with(Default::default);
+        if deprecated.message.is_none() {
+            deprecated.message = Some(message.to_owned());
+        }
+    }
+    tags
+}
+
+/// Appends template arguments of specialization to item name.
+pub fn specialized_name(name: &str, specialization: Option<&str>) -> String {
+    match specialization {
+        Some(arguments) => format!("{}<{}>", name, arguments),
+        None => name.to_owned(),
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemplateParameterKind {
+    #[default]
+    Type,
+    NonType,
+    Pack,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TemplateParameter {
+    #[serde(default)]
+    pub kind: TemplateParameterKind,
+    /// Either `typename`, `class` or template template declaration for type parameters,
+    /// otherwise type of the value.
+    pub value_type: Type,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub doc_comments: Option<String>,
+}
+
+impl TemplateParameter {
+    pub fn signature(&self) -> String {
+        let mut result = self.value_type.to_owned();
+        if self.kind == TemplateParameterKind::Pack {
+            result.push_str("...");
+        }
+        if let Some(name) = &self.name {
+            result.push(' ');
+            result.push_str(name);
+        }
+        if let Some(default_value) = &self.default_value {
+            result.push_str(" = ");
+            result.push_str(default_value);
+        }
+        result
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Template {
+    /// Empty for explicit specializations.
+    #[serde(default)]
+    pub parameters: Vec<TemplateParameter>,
+}
+
+impl Template {
+    pub fn signature(&self) -> String {
+        format!(
+            "template <{}>",
+            self.parameters
+                .iter()
+                .map(|parameter| parameter.signature())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -17,6 +361,46 @@ pub struct Proxy<T> {
     pub item: T,
 }
 
+/// Proxy with parameters in its tag, that gets parsed once instantiated with arguments of
+/// macro invocation, replacing `$Parameter$` placeholders.
+#[derive(Debug, Default, Clone)]
+pub struct ProxyTemplate {
+    pub tag: String,
+    pub parameters: Vec<String>,
+    pub content: String,
+    pub doc_comments: String,
+    pub directives: Directives,
+    pub location: Option<SourceLocation>,
+}
+
+impl ProxyTemplate {
+    /// Returns content and doc comments with placeholders replaced by arguments.
+    pub fn instantiate(&self, arguments: &[String]) -> (String, String) {
+        let mut content = self.content.to_owned();
+        let mut doc_comments = self.doc_comments.to_owned();
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            let placeholder = format!("${}$", parameter);
+            let argument = arguments.get(index).map(|v| v.as_str()).unwrap_or_default();
+            content = content.replace(&placeholder, argument);
+            doc_comments = doc_comments.replace(&placeholder, argument);
+        }
+        (content, doc_comments)
+    }
+}
+
+/// Macro invoked in struct or class body.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct MacroCall {
+    pub name: String,
+    pub arguments: Vec<String>,
+}
+
+impl MacroCall {
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, self.arguments.join(", "))
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Document {
     #[serde(default)]
@@ -26,8 +410,16 @@ pub struct Document {
     #[serde(default)]
     pub structs: Vec<StructClass>,
     #[serde(default)]
+    pub interfaces: Vec<Interface>,
+    #[serde(default)]
+    pub delegates: Vec<Delegate>,
+    #[serde(default)]
+    pub type_aliases: Vec<TypeAlias>,
+    #[serde(default)]
     pub functions: Vec<Function>,
     #[serde(default)]
+    pub variables: Vec<Variable>,
+    #[serde(default)]
     pub book: HashMap<String, String>,
     #[serde(default)]
     pub snippets: HashMap<String, String>,
@@ -35,6 +427,14 @@ pub struct Document {
     pub proxy_functions: Vec<Proxy<Function>>,
     #[serde(skip)]
     pub proxy_properties: Vec<Proxy<Property>>,
+    #[serde(skip)]
+    pub proxy_enums: Vec<Proxy<Enum>>,
+    #[serde(skip)]
+    pub proxy_structs: Vec<Proxy<StructClass>>,
+    #[serde(skip)]
+    pub proxy_delegates: Vec<Proxy<Delegate>>,
+    #[serde(skip)]
+    pub proxy_templates: Vec<ProxyTemplate>,
 }
 
 impl Document {
@@ -45,18 +445,168 @@ impl Document {
         for item in &mut self.structs {
             item.sort_items_by_name();
         }
+        for item in &mut self.interfaces {
+            item.sort_items_by_name();
+        }
+
+        self.enums
+            .sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+        self.classes.sort_by(|a, b| {
+            (&a.namespace, &a.name, &a.specialization).cmp(&(
+                &b.namespace,
+                &b.name,
+                &b.specialization,
+            ))
+        });
+        self.structs.sort_by(|a, b| {
+            (&a.namespace, &a.name, &a.specialization).cmp(&(
+                &b.namespace,
+                &b.name,
+                &b.specialization,
+            ))
+        });
+        self.interfaces
+            .sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+        self.delegates
+            .sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+        self.type_aliases
+            .sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+        self.functions.sort_by(|a, b| {
+            (&a.namespace, &a.name, &a.specialization).cmp(&(
+                &b.namespace,
+                &b.name,
+                &b.specialization,
+            ))
+        });
+        self.variables
+            .sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+    }
+
+    /// Merges native `I`-prefixed classes into their `UINTERFACE` reflection counterparts.
+    pub fn resolve_interfaces(&mut self, settings: &Settings) {
+        for item in &mut self.interfaces {
+            let native_name = item.native_name();
+            if let Some(index) = self
+                .classes
+                .iter()
+                .position(|class| class.namespace == item.namespace && class.name == native_name)
+            {
+                item.merge_native_class(self.classes.remove(index));
+            }
+        }
+        self.interfaces.retain(|item| item.can_export(settings));
+    }
+
+    /// Moves types declared inside structs and classes into document, with their owner set.
+    pub fn resolve_nested_types(&mut self) {
+        let mut enums = vec![];
+        let mut structs = vec![];
+        let mut type_aliases = vec![];
+        let mut delegates = vec![];
+        for item in self.structs.iter_mut().chain(self.classes.iter_mut()) {
+            item.take_nested_types(&mut enums, &mut structs, &mut type_aliases, &mut delegates);
+        }
+        self.enums.extend(enums);
+        self.type_aliases.extend(type_aliases);
+        self.delegates.extend(delegates);
+        for item in structs {
+            match item.mode {
+                StructClassMode::Struct => self.structs.push(item),
+                StructClassMode::Class => self.classes.push(item),
+            }
+        }
+    }
 
-        self.enums.sort_by(|a, b| a.name.cmp(&b.name));
-        self.classes.sort_by(|a, b| a.name.cmp(&b.name));
-        self.structs.sort_by(|a, b| a.name.cmp(&b.name));
-        self.functions.sort_by(|a, b| a.name.cmp(&b.name));
+    /// Injects proxied members into structs, classes and interfaces with matching inject tags,
+    /// where proxied types become their nested types. Returns diagnostics along with files of
+    /// items with inject tags that no proxy has.
+    pub fn resolve_injects(&mut self) -> Vec<(String, Diagnostic)> {
+        let proxies = Proxies {
+            functions: std::mem::take(&mut self.proxy_functions),
+            properties: std::mem::take(&mut self.proxy_properties),
+            enums: std::mem::take(&mut self.proxy_enums),
+            structs: std::mem::take(&mut self.proxy_structs),
+            delegates: std::mem::take(&mut self.proxy_delegates),
+        };
+        let mut result = vec![];
+        let mut injected = Injected::default();
+        for item in self.structs.iter_mut().chain(self.classes.iter_mut()) {
+            result.extend(proxies.inject(
+                std::mem::take(&mut item.injects),
+                &item.macro_calls,
+                &item.namespace,
+                &item.scoped_name(),
+                &item.location,
+                &mut item.methods,
+                &mut item.properties,
+                &mut injected,
+            ));
+        }
+        for item in &mut self.interfaces {
+            result.extend(proxies.inject(
+                std::mem::take(&mut item.injects),
+                &item.macro_calls,
+                &item.namespace,
+                &item.native_name(),
+                &item.location,
+                &mut item.methods,
+                &mut item.properties,
+                &mut injected,
+            ));
+        }
+        while let Some(mut item) = injected.structs.pop() {
+            item.take_nested_types(
+                &mut injected.enums,
+                &mut injected.structs,
+                &mut injected.type_aliases,
+                &mut injected.delegates,
+            );
+            result.extend(proxies.inject(
+                std::mem::take(&mut item.injects),
+                &item.macro_calls,
+                &item.namespace,
+                &item.scoped_name(),
+                &item.location,
+                &mut item.methods,
+                &mut item.properties,
+                &mut injected,
+            ));
+            match item.mode {
+                StructClassMode::Struct => self.structs.push(item),
+                StructClassMode::Class => self.classes.push(item),
+            }
+        }
+        self.enums.extend(injected.enums);
+        self.type_aliases.extend(injected.type_aliases);
+        self.delegates.extend(injected.delegates);
+        result
     }
 
-    pub fn resolve_injects(&mut self) {
-        let proxy_functions = std::mem::take(&mut self.proxy_functions);
-        let proxy_properties = std::mem::take(&mut self.proxy_properties);
+    /// Takes Doxygen tags out of doc comments of all items and their members.
+    pub fn resolve_doc_tags(&mut self) {
+        for item in &mut self.enums {
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.classes {
+            item.resolve_doc_tags();
+        }
         for item in &mut self.structs {
-            item.resolve_injects(&proxy_functions, &proxy_properties);
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.interfaces {
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.delegates {
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.type_aliases {
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.variables {
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.functions {
+            item.resolve_doc_tags();
         }
     }
 
@@ -70,12 +620,132 @@ impl Document {
         for item in &mut self.structs {
             item.resolve_self_names_in_docs();
         }
+        for item in &mut self.interfaces {
+            item.resolve_self_names_in_docs();
+        }
+        for item in &mut self.delegates {
+            item.resolve_self_names_in_docs();
+        }
+        for item in &mut self.type_aliases {
+            item.resolve_self_names_in_docs();
+        }
         for item in &mut self.functions {
             item.resolve_self_names_in_docs(None);
         }
     }
 }
 
+/// Proxied items taken out of document for injection.
+struct Proxies {
+    functions: Vec<Proxy<Function>>,
+    properties: Vec<Proxy<Property>>,
+    enums: Vec<Proxy<Enum>>,
+    structs: Vec<Proxy<StructClass>>,
+    delegates: Vec<Proxy<Delegate>>,
+}
+
+impl Proxies {
+    /// Adds proxied members with any of given tags, or names of macros invoked in item, to item
+    /// of given scope, collecting proxied types as its nested types. Returns diagnostics along
+    /// with file of item about tags that no proxy has.
+    #[allow(clippy::too_many_arguments)]
+    fn inject(
+        &self,
+        mut tags: HashSet<String>,
+        macro_calls: &[MacroCall],
+        namespace: &Option<String>,
+        owner: &str,
+        location: &Option<SourceLocation>,
+        methods: &mut Vec<Function>,
+        properties: &mut Vec<Property>,
+        injected: &mut Injected,
+    ) -> Vec<(String, Diagnostic)> {
+        tags.extend(
+            macro_calls
+                .iter()
+                .filter(|call| self.has_tag(&call.name))
+                .map(|call| call.name.to_owned()),
+        );
+        let is_tagged =
+            |proxy_tags: &HashSet<String>| tags.iter().any(|tag| proxy_tags.contains(tag));
+        for proxy in &self.functions {
+            if is_tagged(&proxy.tags) {
+                methods.push(proxy.item.to_owned());
+            }
+        }
+        for proxy in &self.properties {
+            if is_tagged(&proxy.tags) {
+                properties.push(proxy.item.to_owned());
+            }
+        }
+        for proxy in &self.enums {
+            if is_tagged(&proxy.tags) {
+                let mut item = proxy.item.to_owned();
+                item.namespace = namespace.to_owned();
+                item.owner = Some(owner.to_owned());
+                injected.enums.push(item);
+            }
+        }
+        for proxy in &self.structs {
+            // Struct injecting its own tag would nest itself endlessly.
+            if is_tagged(&proxy.tags) && !owner.split("::").any(|name| name == proxy.item.name) {
+                let mut item = proxy.item.to_owned();
+                item.namespace = namespace.to_owned();
+                item.owner = Some(owner.to_owned());
+                injected.structs.push(item);
+            }
+        }
+        for proxy in &self.delegates {
+            if is_tagged(&proxy.tags) {
+                let mut item = proxy.item.to_owned();
+                item.namespace = namespace.to_owned();
+                item.owner = Some(owner.to_owned());
+                injected.delegates.push(item);
+            }
+        }
+        let (file, line) = location
+            .as_ref()
+            .map(|location| (location.file.to_owned(), location.start_line))
+            .unwrap_or_default();
+        let mut tags = tags
+            .into_iter()
+            .filter(|tag| !self.has_tag(tag))
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.into_iter()
+            .map(|tag| {
+                let diagnostic = Diagnostic {
+                    line,
+                    column: 1,
+                    message: format!(
+                        "Unmatched inject tag `{}` in: {}",
+                        tag,
+                        qualified_name(namespace.as_deref(), owner)
+                    ),
+                };
+                (file.to_owned(), diagnostic)
+            })
+            .collect()
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.functions.iter().any(|proxy| proxy.tags.contains(tag))
+            || self.properties.iter().any(|proxy| proxy.tags.contains(tag))
+            || self.enums.iter().any(|proxy| proxy.tags.contains(tag))
+            || self.structs.iter().any(|proxy| proxy.tags.contains(tag))
+            || self.delegates.iter().any(|proxy| proxy.tags.contains(tag))
+    }
+}
+
+/// Types injected into items, waiting to be added to document.
+#[derive(Default)]
+struct Injected {
+    enums: Vec<Enum>,
+    structs: Vec<StructClass>,
+    type_aliases: Vec<TypeAlias>,
+    delegates: Vec<Delegate>,
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Specifiers {
     #[serde(default)]
@@ -90,6 +760,46 @@ pub enum Attribute {
     Pair { key: String, value: String },
 }
 
+impl Specifiers {
+    /// Deprecation declared with `Deprecated` specifier or `DeprecatedFunction`,
+    /// `DeprecatedProperty` and `DeprecationMessage` meta specifiers.
+    pub fn deprecation(&self) -> Option<Deprecation> {
+        let deprecated = self.attributes.iter().any(|attribute| {
+            matches!(attribute, Attribute::Single(name) if name.eq_ignore_ascii_case("Deprecated"))
+        });
+        let deprecated_meta = self.meta.iter().any(|attribute| {
+            matches!(
+                attribute,
+                Attribute::Single(name) if name.eq_ignore_ascii_case("DeprecatedFunction")
+                    || name.eq_ignore_ascii_case("DeprecatedProperty")
+            )
+        });
+        let message = self.meta.iter().find_map(|attribute| match attribute {
+            Attribute::Pair { key, value } if key.eq_ignore_ascii_case("DeprecationMessage") => {
+                Some(value.to_owned())
+            }
+            _ => None,
+        });
+        if deprecated || deprecated_meta || message.is_some() {
+            Some(Deprecation {
+                version: None,
+                message,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Deprecation {
+    /// Engine or project version item got deprecated in.
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Visibility {
     Private,
@@ -120,151 +830,694 @@ impl Visibility {
 pub struct Enum {
     #[serde(default)]
     pub specifiers: Option<Specifiers>,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// Path of the type this enum is declared in.
+    #[serde(default)]
+    pub owner: Option<String>,
+    pub name: String,
+    /// Name of the inner enum type of legacy `namespace EFoo { enum Type { ... }; }` pattern.
+    #[serde(default)]
+    pub legacy_name: Option<String>,
+    /// Either `class` or `struct` keyword of scoped enum.
+    #[serde(default)]
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub underlying_type: Option<Type>,
+    #[serde(default)]
+    pub variants: Vec<EnumVariant>,
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    /// Preprocessor condition this item is compiled under.
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
+    #[serde(default)]
+    pub doc_comments: Option<String>,
+}
+
+impl Enum {
+    pub fn can_export(&self, settings: &Settings) -> bool {
+        !self.directives.hidden && (settings.show_all || self.doc_comments.is_some())
+    }
+
+    pub fn full_name(&self) -> String {
+        qualified_name(self.namespace.as_deref(), &self.scoped_name())
+    }
+
+    pub fn scoped_name(&self) -> String {
+        qualified_name(self.owner.as_deref(), &self.name)
+    }
+
+    pub fn signature(&self) -> String {
+        let indent = if self.legacy_name.is_some() {
+            "    "
+        } else {
+            ""
+        };
+        let variants = self
+            .variants
+            .iter()
+            .map(|v| format!("{}    {}", indent, v.signature()))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let mut result = String::new();
+        if self.legacy_name.is_some() {
+            result.push_str(&format!("namespace {} {{\n", self.name));
+        }
+        result.push_str(indent);
+        result.push_str("enum ");
+        if let Some(scope) = &self.scope {
+            result.push_str(scope);
+            result.push(' ');
+        }
+        result.push_str(self.legacy_name.as_deref().unwrap_or(&self.name));
+        if let Some(underlying_type) = &self.underlying_type {
+            result.push_str(" : ");
+            result.push_str(underlying_type);
+        }
+        result.push_str(&format!(" {{\n{}\n{}}};", variants, indent));
+        if self.legacy_name.is_some() {
+            result.push_str("\n}");
+        }
+        result
+    }
+
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+        for item in &mut self.variants {
+            item.resolve_doc_tags();
+        }
+    }
+
+    pub fn resolve_self_names_in_docs(&mut self) {
+        let name = self.scoped_name();
+        if let Some(content) = &mut self.doc_comments {
+            *content = replace_self_names(content, &name);
+        }
+        for item in &mut self.variants {
+            item.resolve_self_names_in_docs(&name);
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct EnumVariant {
+    pub name: String,
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default)]
+    pub specifiers: Option<Specifiers>,
+    #[serde(default)]
+    pub doc_tags: DocTags,
+    #[serde(default)]
+    pub doc_comments: Option<String>,
+}
+
+impl EnumVariant {
+    pub fn signature(&self) -> String {
+        match &self.value {
+            Some(value) => format!("{} = {}", self.name, value),
+            None => self.name.to_owned(),
+        }
+    }
+
+    /// Display name provided with `UMETA(DisplayName = "...")`.
+    pub fn display_name(&self) -> Option<&str> {
+        self.specifiers.as_ref().and_then(|specifiers| {
+            specifiers
+                .attributes
+                .iter()
+                .find_map(|attribute| match attribute {
+                    Attribute::Pair { key, value } if key.eq_ignore_ascii_case("DisplayName") => {
+                        Some(value.as_str())
+                    }
+                    _ => None,
+                })
+        })
+    }
+
+    pub fn resolve_doc_tags(&mut self) {
+        if let Some(content) = &mut self.doc_comments {
+            let (text, tags) = DocTags::extract(content);
+            *content = text;
+            self.doc_tags = tags;
+        }
+    }
+
+    pub fn resolve_self_names_in_docs(&mut self, owner: &str) {
+        if let Some(content) = &mut self.doc_comments {
+            *content = replace_self_names(content, owner);
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StructClassMode {
+    #[default]
+    Struct,
+    Class,
+}
+
+impl StructClassMode {
+    pub fn default_visibility(self) -> Visibility {
+        match self {
+            Self::Struct => Visibility::Public,
+            Self::Class => Visibility::Private,
+        }
+    }
+
+    pub fn signature(self) -> String {
+        match self {
+            Self::Struct => "struct".to_owned(),
+            Self::Class => "class".to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct StructClass {
+    #[serde(default)]
+    pub specifiers: Option<Specifiers>,
+    #[serde(default)]
+    pub api: Option<String>,
+    pub mode: StructClassMode,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// Path of the type this struct or class is declared in.
+    #[serde(default)]
+    pub owner: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub inherits: Vec<(Visibility, String)>,
+    #[serde(default)]
+    pub template: Option<Template>,
+    /// Template arguments of explicit or partial specialization.
+    #[serde(default)]
+    pub specialization: Option<String>,
+    #[serde(default)]
+    pub properties: Vec<Property>,
+    #[serde(default)]
+    pub methods: Vec<Function>,
+    /// Has pure virtual methods or is marked with `Abstract` specifier.
+    #[serde(default)]
+    pub is_abstract: bool,
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
+    #[serde(default)]
+    pub doc_comments: Option<String>,
+    #[serde(skip)]
+    pub injects: HashSet<String>,
+    #[serde(skip)]
+    pub macro_calls: Vec<MacroCall>,
+    #[serde(skip)]
+    pub nested_enums: Vec<Enum>,
+    #[serde(skip)]
+    pub nested_structs: Vec<StructClass>,
+    #[serde(skip)]
+    pub nested_type_aliases: Vec<TypeAlias>,
+    #[serde(skip)]
+    pub nested_delegates: Vec<Delegate>,
+}
+
+impl StructClass {
+    pub fn can_export(&self, settings: &Settings) -> bool {
+        !self.directives.hidden
+            && (settings.show_all
+                || self.doc_comments.is_some()
+                || self.properties.iter().any(|e| e.can_export(settings))
+                || self.methods.iter().any(|e| e.can_export(settings))
+                || !self.nested_enums.is_empty()
+                || !self.nested_structs.is_empty()
+                || !self.nested_type_aliases.is_empty()
+                || !self.nested_delegates.is_empty())
+    }
+
+    pub fn full_name(&self) -> String {
+        qualified_name(self.namespace.as_deref(), &self.scoped_name())
+    }
+
+    pub fn scoped_name(&self) -> String {
+        qualified_name(
+            self.owner.as_deref(),
+            &specialized_name(&self.name, self.specialization.as_deref()),
+        )
+    }
+
+    /// Tells if this is primary template of given specialization.
+    pub fn is_primary_template_of(&self, item: &StructClass) -> bool {
+        self.specialization.is_none()
+            && item.specialization.is_some()
+            && self.template.is_some()
+            && self.mode == item.mode
+            && self.namespace == item.namespace
+            && self.owner == item.owner
+            && self.name == item.name
+    }
+
+    fn take_nested_types(
+        &mut self,
+        enums: &mut Vec<Enum>,
+        structs: &mut Vec<StructClass>,
+        type_aliases: &mut Vec<TypeAlias>,
+        delegates: &mut Vec<Delegate>,
+    ) {
+        let owner = self.scoped_name();
+        for mut item in std::mem::take(&mut self.nested_enums) {
+            item.namespace = self.namespace.to_owned();
+            item.owner = Some(owner.to_owned());
+            enums.push(item);
+        }
+        for mut item in std::mem::take(&mut self.nested_type_aliases) {
+            item.namespace = self.namespace.to_owned();
+            item.owner = Some(owner.to_owned());
+            type_aliases.push(item);
+        }
+        for mut item in std::mem::take(&mut self.nested_delegates) {
+            item.namespace = self.namespace.to_owned();
+            item.owner = Some(owner.to_owned());
+            delegates.push(item);
+        }
+        for mut item in std::mem::take(&mut self.nested_structs) {
+            item.namespace = self.namespace.to_owned();
+            item.owner = Some(owner.to_owned());
+            item.take_nested_types(enums, structs, type_aliases, delegates);
+            structs.push(item);
+        }
+    }
+
+    pub fn signature(&self) -> String {
+        let mut result = String::new();
+        if let Some(template) = &self.template {
+            result.push_str(&template.signature());
+            result.push('\n');
+        }
+        result.push_str(&self.mode.signature());
+        result.push(' ');
+        if let Some(api) = &self.api {
+            result.push_str(api);
+            result.push(' ');
+        }
+        result.push_str(&specialized_name(
+            &self.name,
+            self.specialization.as_deref(),
+        ));
+        if !self.inherits.is_empty() {
+            for (i, (visibility, name)) in self.inherits.iter().enumerate() {
+                result.push('\n');
+                result.push_str("    ");
+                if i == 0 {
+                    result.push_str(": ");
+                } else {
+                    result.push_str(", ");
+                }
+                result.push_str(&visibility.signature());
+                result.push(' ');
+                result.push_str(name);
+            }
+        }
+        result.push(';');
+        result
+    }
+
+    pub fn sort_items_by_name(&mut self) {
+        self.properties.sort_by(|a, b| a.name.cmp(&b.name));
+        self.methods.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+        self.doc_tags.apply_to_template(&mut self.template);
+        for item in &mut self.methods {
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.properties {
+            item.resolve_doc_tags();
+        }
+    }
+
+    pub fn resolve_self_names_in_docs(&mut self) {
+        let name = self.scoped_name();
+        if let Some(content) = &mut self.doc_comments {
+            *content = replace_self_names(content, &name);
+        }
+        for item in &mut self.properties {
+            item.resolve_self_names_in_docs(&name);
+        }
+        for item in &mut self.methods {
+            item.resolve_self_names_in_docs(Some(&name));
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Interface {
+    #[serde(default)]
+    pub specifiers: Option<Specifiers>,
+    #[serde(default)]
+    pub api: Option<String>,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// Interface name without `U` or `I` prefix.
+    pub name: String,
+    #[serde(default)]
+    pub inherits: Vec<(Visibility, String)>,
+    #[serde(default)]
+    pub properties: Vec<Property>,
+    #[serde(default)]
+    pub methods: Vec<Function>,
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
+    #[serde(default)]
+    pub doc_comments: Option<String>,
+    #[serde(skip)]
+    pub injects: HashSet<String>,
+    #[serde(skip)]
+    pub macro_calls: Vec<MacroCall>,
+}
+
+impl Interface {
+    pub fn from_reflection_class(item: StructClass) -> Self {
+        let name = item.name.strip_prefix('U').unwrap_or(&item.name).to_owned();
+        Self {
+            specifiers: item.specifiers,
+            api: item.api,
+            namespace: item.namespace,
+            name,
+            inherits: vec![],
+            properties: item.properties,
+            methods: item.methods,
+            deprecated: item.deprecated,
+            condition: item.condition,
+            location: item.location,
+            directives: item.directives,
+            doc_tags: item.doc_tags,
+            doc_comments: item.doc_comments,
+            injects: item.injects,
+            macro_calls: item.macro_calls,
+        }
+    }
+
+    pub fn merge_native_class(&mut self, item: StructClass) {
+        if item.api.is_some() {
+            self.api = item.api;
+        }
+        self.inherits = item.inherits;
+        if item.deprecated.is_some() {
+            self.deprecated = item.deprecated;
+        }
+        if item.condition.is_some() {
+            self.condition = item.condition;
+        }
+        self.properties.extend(item.properties);
+        self.methods.extend(item.methods);
+        self.doc_comments = match (self.doc_comments.take(), item.doc_comments) {
+            (Some(a), Some(b)) => Some(format!("{}\n\n{}", a, b)),
+            (a, b) => a.or(b),
+        };
+        self.injects.extend(item.injects);
+        self.macro_calls.extend(item.macro_calls);
+        self.directives.merge(item.directives);
+    }
+
+    pub fn can_export(&self, settings: &Settings) -> bool {
+        !self.directives.hidden
+            && (settings.show_all
+                || self.doc_comments.is_some()
+                || self.properties.iter().any(|e| e.can_export(settings))
+                || self.methods.iter().any(|e| e.can_export(settings)))
+    }
+
+    pub fn full_name(&self) -> String {
+        qualified_name(self.namespace.as_deref(), &self.name)
+    }
+
+    pub fn reflection_name(&self) -> String {
+        format!("U{}", self.name)
+    }
+
+    pub fn native_name(&self) -> String {
+        format!("I{}", self.name)
+    }
+
+    pub fn signature(&self) -> String {
+        let mut result = String::new();
+        result.push_str("class ");
+        result.push_str(&self.reflection_name());
+        result.push_str("\n    : public UInterface;\n\nclass ");
+        if let Some(api) = &self.api {
+            result.push_str(api);
+            result.push(' ');
+        }
+        result.push_str(&self.native_name());
+        for (i, (visibility, name)) in self.inherits.iter().enumerate() {
+            result.push('\n');
+            result.push_str("    ");
+            if i == 0 {
+                result.push_str(": ");
+            } else {
+                result.push_str(", ");
+            }
+            result.push_str(&visibility.signature());
+            result.push(' ');
+            result.push_str(name);
+        }
+        result.push(';');
+        result
+    }
+
+    pub fn sort_items_by_name(&mut self) {
+        self.properties.sort_by(|a, b| a.name.cmp(&b.name));
+        self.methods.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+        for item in &mut self.methods {
+            item.resolve_doc_tags();
+        }
+        for item in &mut self.properties {
+            item.resolve_doc_tags();
+        }
+    }
+
+    pub fn resolve_self_names_in_docs(&mut self) {
+        let native_name = self.native_name();
+        if let Some(content) = &mut self.doc_comments {
+            *content = replace_self_names(content, &native_name);
+        }
+        for item in &mut self.properties {
+            item.resolve_self_names_in_docs(&native_name);
+        }
+        for item in &mut self.methods {
+            item.resolve_self_names_in_docs(Some(&native_name));
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Delegate {
+    #[serde(default)]
+    pub namespace: Option<String>,
+    /// Path of the type this delegate is declared in.
+    #[serde(default)]
+    pub owner: Option<String>,
     pub name: String,
     #[serde(default)]
-    pub variants: Vec<String>,
+    pub return_type: Option<Type>,
+    #[serde(default)]
+    pub is_dynamic: bool,
+    #[serde(default)]
+    pub is_multicast: bool,
+    #[serde(default)]
+    pub is_sparse: bool,
+    /// Owning class and property name of sparse delegate.
+    #[serde(default)]
+    pub sparse_owner: Option<(String, String)>,
+    #[serde(default)]
+    pub arguments: Vec<Argument>,
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
     #[serde(default)]
     pub doc_comments: Option<String>,
 }
 
-impl Enum {
+impl Delegate {
     pub fn can_export(&self, settings: &Settings) -> bool {
-        settings.show_all || self.doc_comments.is_some()
+        !self.directives.hidden && (settings.show_all || self.doc_comments.is_some())
     }
 
-    pub fn signature(&self) -> String {
-        let variants = self
-            .variants
-            .iter()
-            .map(|v| format!("    {}", v))
-            .collect::<Vec<_>>()
-            .join(",\n");
-        format!("enum class {} : uint8 {{\n{}\n}};", self.name, variants)
+    pub fn full_name(&self) -> String {
+        qualified_name(self.namespace.as_deref(), &self.scoped_name())
     }
 
-    pub fn resolve_self_names_in_docs(&mut self) {
-        if let Some(content) = &mut self.doc_comments {
-            *content = replace_self_names(content, &self.name);
-        }
+    pub fn scoped_name(&self) -> String {
+        qualified_name(self.owner.as_deref(), &self.name)
     }
-}
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub enum StructClassMode {
-    #[default]
-    Struct,
-    Class,
-}
+    pub fn macro_name(&self) -> String {
+        const PARAMS: [&str; 10] = [
+            "",
+            "OneParam",
+            "TwoParams",
+            "ThreeParams",
+            "FourParams",
+            "FiveParams",
+            "SixParams",
+            "SevenParams",
+            "EightParams",
+            "NineParams",
+        ];
+        let mut result = "DECLARE_".to_owned();
+        if self.is_dynamic {
+            result.push_str("DYNAMIC_");
+        }
+        if self.is_multicast {
+            result.push_str("MULTICAST_");
+        }
+        if self.is_sparse {
+            result.push_str("SPARSE_");
+        }
+        result.push_str("DELEGATE");
+        if self.return_type.is_some() {
+            result.push_str("_RetVal");
+        }
+        if let Some(params) = PARAMS.get(self.arguments.len()) {
+            if !params.is_empty() {
+                result.push('_');
+                result.push_str(params);
+            }
+        }
+        result
+    }
 
-impl StructClassMode {
-    pub fn default_visibility(self) -> Visibility {
-        match self {
-            Self::Struct => Visibility::Public,
-            Self::Class => Visibility::Private,
+    pub fn signature(&self) -> String {
+        let mut lines = vec![];
+        if let Some(return_type) = &self.return_type {
+            lines.push(return_type.to_owned());
+        }
+        lines.push(self.name.to_owned());
+        if let Some((owner, property)) = &self.sparse_owner {
+            lines.push(owner.to_owned());
+            lines.push(property.to_owned());
+        }
+        for argument in &self.arguments {
+            match &argument.name {
+                Some(name) => lines.push(format!("{}, {}", argument.value_type, name)),
+                None => lines.push(argument.value_type.to_owned()),
+            }
         }
+        format!("{}(\n    {}\n);", self.macro_name(), lines.join(",\n    "))
     }
 
-    pub fn signature(self) -> String {
-        match self {
-            Self::Struct => "struct".to_owned(),
-            Self::Class => "class".to_owned(),
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+        self.doc_tags.apply_to_arguments(&mut self.arguments);
+    }
+
+    pub fn resolve_self_names_in_docs(&mut self) {
+        if let Some(content) = &mut self.doc_comments {
+            *content = replace_self_names(content, &self.name);
+        }
+        for item in &mut self.arguments {
+            item.resolve_self_names_in_docs(Some(&self.name));
         }
     }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
-pub struct StructClass {
+pub struct TypeAlias {
     #[serde(default)]
-    pub specifiers: Option<Specifiers>,
+    pub namespace: Option<String>,
+    /// Path of the type this alias is declared in.
     #[serde(default)]
-    pub api: Option<String>,
-    pub mode: StructClassMode,
+    pub owner: Option<String>,
     pub name: String,
-    #[serde(default)]
-    pub inherits: Vec<(Visibility, String)>,
+    pub target: Type,
     #[serde(default)]
     pub template: Option<Template>,
+    /// Declared with `typedef` instead of `using`.
     #[serde(default)]
-    pub properties: Vec<Property>,
+    pub is_typedef: bool,
     #[serde(default)]
-    pub methods: Vec<Function>,
+    pub deprecated: Option<Deprecation>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
     #[serde(default)]
     pub doc_comments: Option<String>,
-    #[serde(skip)]
-    pub injects: HashSet<String>,
 }
 
-impl StructClass {
+impl TypeAlias {
     pub fn can_export(&self, settings: &Settings) -> bool {
-        settings.show_all
-            || self.doc_comments.is_some()
-            || self.properties.iter().any(|e| e.can_export(settings))
-            || self.methods.iter().any(|e| e.can_export(settings))
+        !self.directives.hidden && (settings.show_all || self.doc_comments.is_some())
+    }
+
+    pub fn full_name(&self) -> String {
+        qualified_name(self.namespace.as_deref(), &self.scoped_name())
+    }
+
+    pub fn scoped_name(&self) -> String {
+        qualified_name(self.owner.as_deref(), &self.name)
     }
 
     pub fn signature(&self) -> String {
         let mut result = String::new();
         if let Some(template) = &self.template {
-            result.push_str(template);
+            result.push_str(&template.signature());
             result.push('\n');
         }
-        result.push_str(&self.mode.signature());
-        result.push(' ');
-        if let Some(api) = &self.api {
-            result.push_str(api);
-            result.push(' ');
-        }
-        result.push_str(&self.name);
-        if !self.inherits.is_empty() {
-            for (i, (visibility, name)) in self.inherits.iter().enumerate() {
-                result.push('\n');
-                result.push_str("    ");
-                if i == 0 {
-                    result.push_str(": ");
-                } else {
-                    result.push_str(", ");
-                }
-                result.push_str(&visibility.signature());
-                result.push(' ');
-                result.push_str(name);
-            }
+        if self.is_typedef {
+            result.push_str(&format!("typedef {} {};", self.target, self.name));
+        } else {
+            result.push_str(&format!("using {} = {};", self.name, self.target));
         }
-        result.push(';');
         result
     }
 
-    pub fn sort_items_by_name(&mut self) {
-        self.properties.sort_by(|a, b| a.name.cmp(&b.name));
-        self.methods.sort_by(|a, b| a.name.cmp(&b.name));
-    }
-
-    pub fn resolve_injects(
-        &mut self,
-        functions: &[Proxy<Function>],
-        properties: &[Proxy<Property>],
-    ) {
-        let tags = std::mem::take(&mut self.injects);
-        for item in functions {
-            if tags.iter().any(|tag| item.tags.contains(tag)) {
-                self.methods.push(item.item.to_owned());
-            }
-        }
-        for item in properties {
-            if tags.iter().any(|tag| item.tags.contains(tag)) {
-                self.properties.push(item.item.to_owned());
-            }
-        }
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+        self.doc_tags.apply_to_template(&mut self.template);
     }
 
     pub fn resolve_self_names_in_docs(&mut self) {
+        let name = self.scoped_name();
         if let Some(content) = &mut self.doc_comments {
-            *content = replace_self_names(content, &self.name);
-        }
-        for item in &mut self.properties {
-            item.resolve_self_names_in_docs(&self.name);
-        }
-        for item in &mut self.methods {
-            item.resolve_self_names_in_docs(Some(&self.name));
+            *content = replace_self_names(content, &name);
         }
     }
 }
@@ -287,17 +1540,48 @@ pub struct Property {
     pub array: PropertyArray,
     #[serde(default)]
     pub default_value: Option<String>,
+    /// Initialized with braces instead of `=`.
+    #[serde(default)]
+    pub is_brace_initialized: bool,
     #[serde(default)]
     pub visibility: Visibility,
+    /// Width of bitfield property.
+    #[serde(default)]
+    pub bit_width: Option<String>,
+    /// Export macro of namespace scope variable.
+    #[serde(default)]
+    pub api: Option<String>,
+    #[serde(default)]
+    pub is_extern: bool,
     #[serde(default)]
     pub is_static: bool,
     #[serde(default)]
+    pub is_inline: bool,
+    #[serde(default)]
+    pub is_constexpr: bool,
+    #[serde(default)]
+    pub is_thread_local: bool,
+    #[serde(default)]
+    pub is_mutable: bool,
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
+    #[serde(default)]
     pub doc_comments: Option<String>,
 }
 
 impl Property {
     pub fn can_export(&self, settings: &Settings) -> bool {
-        self.doc_comments.is_some() && self.visibility.can_export(settings)
+        !self.directives.hidden
+            && self.doc_comments.is_some()
+            && self.visibility.can_export(settings)
     }
 
     pub fn signature(&self) -> String {
@@ -306,6 +1590,18 @@ impl Property {
         if self.is_static {
             result.push_str("static ");
         }
+        if self.is_inline {
+            result.push_str("inline ");
+        }
+        if self.is_constexpr {
+            result.push_str("constexpr ");
+        }
+        if self.is_thread_local {
+            result.push_str("thread_local ");
+        }
+        if self.is_mutable {
+            result.push_str("mutable ");
+        }
         result.push_str(&self.value_type);
         result.push(' ');
         result.push_str(&self.name);
@@ -314,10 +1610,23 @@ impl Property {
             PropertyArray::Unsized => result.push_str("[]"),
             PropertyArray::Sized(size) => result.push_str(&format!("[{}]", size)),
         }
+        if let Some(bit_width) = &self.bit_width {
+            result.push_str(" : ");
+            result.push_str(bit_width);
+        }
         result.push(';');
         result
     }
 
+    /// Tells if property is single bit flag, the way Unreal declares boolean properties.
+    pub fn is_bool_flag(&self) -> bool {
+        self.bit_width.as_deref() == Some("1")
+    }
+
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+    }
+
     pub fn resolve_self_names_in_docs(&mut self, owner: &str) {
         if let Some(content) = &mut self.doc_comments {
             *content = replace_self_names(content, owner);
@@ -325,16 +1634,140 @@ impl Property {
     }
 }
 
+/// Namespace scope variable or constant.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Variable {
+    #[serde(default)]
+    pub api: Option<String>,
+    #[serde(default)]
+    pub namespace: Option<String>,
+    pub name: String,
+    pub value_type: Type,
+    #[serde(default)]
+    pub array: PropertyArray,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub is_brace_initialized: bool,
+    #[serde(default)]
+    pub is_extern: bool,
+    #[serde(default)]
+    pub is_static: bool,
+    #[serde(default)]
+    pub is_inline: bool,
+    #[serde(default)]
+    pub is_constexpr: bool,
+    #[serde(default)]
+    pub is_thread_local: bool,
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
+    #[serde(default)]
+    pub doc_comments: Option<String>,
+}
+
+impl Variable {
+    pub fn from_property(item: Property) -> Self {
+        Self {
+            api: item.api,
+            namespace: None,
+            name: item.name,
+            value_type: item.value_type,
+            array: item.array,
+            default_value: item.default_value,
+            is_brace_initialized: item.is_brace_initialized,
+            is_extern: item.is_extern,
+            is_static: item.is_static,
+            is_inline: item.is_inline,
+            is_constexpr: item.is_constexpr,
+            is_thread_local: item.is_thread_local,
+            deprecated: item.deprecated,
+            condition: item.condition,
+            location: item.location,
+            directives: item.directives,
+            doc_tags: item.doc_tags,
+            doc_comments: item.doc_comments,
+        }
+    }
+
+    pub fn can_export(&self, settings: &Settings) -> bool {
+        !self.directives.hidden && (settings.show_all || self.doc_comments.is_some())
+    }
+
+    pub fn full_name(&self) -> String {
+        qualified_name(self.namespace.as_deref(), &self.name)
+    }
+
+    /// Tells if variable value can not change.
+    pub fn is_constant(&self) -> bool {
+        self.is_constexpr || self.value_type.starts_with("const ")
+    }
+
+    pub fn signature(&self) -> String {
+        let mut result = String::new();
+        if self.is_extern {
+            result.push_str("extern ");
+        }
+        if let Some(api) = &self.api {
+            result.push_str(api);
+            result.push(' ');
+        }
+        if self.is_static {
+            result.push_str("static ");
+        }
+        if self.is_inline {
+            result.push_str("inline ");
+        }
+        if self.is_constexpr {
+            result.push_str("constexpr ");
+        }
+        if self.is_thread_local {
+            result.push_str("thread_local ");
+        }
+        result.push_str(&self.value_type);
+        result.push(' ');
+        result.push_str(&self.name);
+        match &self.array {
+            PropertyArray::None => {}
+            PropertyArray::Unsized => result.push_str("[]"),
+            PropertyArray::Sized(size) => result.push_str(&format!("[{}]", size)),
+        }
+        match &self.default_value {
+            Some(value) if self.is_brace_initialized => result.push_str(value),
+            Some(value) => result.push_str(&format!(" = {}", value)),
+            None => {}
+        }
+        result.push(';');
+        result
+    }
+
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Function {
     #[serde(default)]
     pub specifiers: Option<Specifiers>,
+    #[serde(default)]
+    pub namespace: Option<String>,
     pub name: String,
     pub return_type: Option<Type>,
     #[serde(default)]
     pub visibility: Visibility,
     #[serde(default)]
     pub template: Option<Template>,
+    /// Template arguments of explicit specialization.
+    #[serde(default)]
+    pub specialization: Option<String>,
     #[serde(default)]
     pub arguments: Vec<Argument>,
     #[serde(default)]
@@ -342,28 +1775,104 @@ pub struct Function {
     #[serde(default)]
     pub is_virtual: bool,
     #[serde(default)]
+    pub is_inline: bool,
+    /// Marked with `FORCEINLINE` macro.
+    #[serde(default)]
+    pub is_force_inline: bool,
+    #[serde(default)]
+    pub is_explicit: bool,
+    #[serde(default)]
+    pub is_constexpr: bool,
+    /// Either `[[nodiscard]]` attribute or `UE_NODISCARD` macro, as spelled in declaration.
+    #[serde(default)]
+    pub nodiscard: Option<String>,
+    #[serde(default)]
     pub is_const_this: bool,
+    /// Either `&` or `&&` qualifier of `this`.
+    #[serde(default)]
+    pub ref_qualifier: Option<String>,
+    /// Noexcept specification, with its condition if provided.
+    #[serde(default)]
+    pub noexcept: Option<String>,
+    /// Return type provided after `->`.
+    #[serde(default)]
+    pub trailing_return_type: Option<Type>,
     #[serde(default)]
     pub is_override: bool,
     #[serde(default)]
+    pub is_final: bool,
+    /// Declared with `= 0`.
+    #[serde(default)]
+    pub is_pure_virtual: bool,
+    /// Declared with `= default`.
+    #[serde(default)]
+    pub is_defaulted: bool,
+    /// Declared with `= delete`.
+    #[serde(default)]
+    pub is_deleted: bool,
+    #[serde(default)]
+    pub deprecated: Option<Deprecation>,
+    #[serde(default)]
+    pub condition: Option<String>,
+    #[serde(default)]
+    pub location: Option<SourceLocation>,
+    #[serde(default)]
+    pub directives: Directives,
+    #[serde(default)]
+    pub doc_tags: DocTags,
+    #[serde(default)]
     pub doc_comments: Option<String>,
 }
 
 impl Function {
     pub fn can_export(&self, settings: &Settings) -> bool {
-        self.doc_comments.is_some() && self.visibility.can_export(settings)
+        !self.directives.hidden
+            && self.doc_comments.is_some()
+            && self.visibility.can_export(settings)
+    }
+
+    pub fn full_name(&self) -> String {
+        qualified_name(
+            self.namespace.as_deref(),
+            &specialized_name(&self.name, self.specialization.as_deref()),
+        )
+    }
+
+    /// Tells if this is primary template of given specialization.
+    pub fn is_primary_template_of(&self, item: &Function) -> bool {
+        self.specialization.is_none()
+            && item.specialization.is_some()
+            && self.template.is_some()
+            && self.namespace == item.namespace
+            && self.name == item.name
     }
 
     pub fn signature(&self) -> String {
         let mut result = self.visibility.signature();
         result.push_str(":\n");
         if let Some(template) = &self.template {
-            result.push_str(template);
+            result.push_str(&template.signature());
             result.push('\n');
         }
+        if let Some(nodiscard) = &self.nodiscard {
+            result.push_str(nodiscard);
+            result.push(' ');
+        }
         if self.is_static {
             result.push_str("static ");
         }
+        if self.is_inline {
+            result.push_str("inline ");
+        }
+        if self.is_force_inline {
+            result.push_str("FORCEINLINE ");
+        }
+        if self.is_explicit {
+            result.push_str("explicit ");
+        }
+        if self.is_constexpr {
+            result.push_str("constexpr ");
+        }
         if self.is_virtual {
             result.push_str("virtual ");
         }
@@ -371,7 +1880,10 @@ impl Function {
             result.push_str(return_type);
             result.push(' ');
         }
-        result.push_str(&self.name);
+        result.push_str(&specialized_name(
+            &self.name,
+            self.specialization.as_deref(),
+        ));
         result.push('(');
         for (i, argument) in self.arguments.iter().enumerate() {
             result.push_str("\n    ");
@@ -386,13 +1898,41 @@ impl Function {
         if self.is_const_this {
             result.push_str(" const");
         }
+        if let Some(ref_qualifier) = &self.ref_qualifier {
+            result.push(' ');
+            result.push_str(ref_qualifier);
+        }
+        if let Some(noexcept) = &self.noexcept {
+            result.push(' ');
+            result.push_str(noexcept);
+        }
+        if let Some(trailing_return_type) = &self.trailing_return_type {
+            result.push_str(" -> ");
+            result.push_str(trailing_return_type);
+        }
         if self.is_override {
             result.push_str(" override");
         }
+        if self.is_final {
+            result.push_str(" final");
+        }
+        if self.is_pure_virtual {
+            result.push_str(" = 0");
+        } else if self.is_defaulted {
+            result.push_str(" = default");
+        } else if self.is_deleted {
+            result.push_str(" = delete");
+        }
         result.push(';');
         result
     }
 
+    pub fn resolve_doc_tags(&mut self) {
+        self.doc_tags = resolve_doc_tags(&mut self.doc_comments, &mut self.deprecated);
+        self.doc_tags.apply_to_template(&mut self.template);
+        self.doc_tags.apply_to_arguments(&mut self.arguments);
+    }
+
     pub fn resolve_self_names_in_docs(&mut self, owner: Option<&str>) {
         if let (Some(owner), Some(content)) = (owner, &mut self.doc_comments) {
             *content = replace_self_names(content, owner);
@@ -411,10 +1951,53 @@ pub struct Argument {
     #[serde(default)]
     pub default_value: Option<String>,
     #[serde(default)]
+    pub specifiers: Option<Specifiers>,
+    #[serde(default)]
     pub doc_comments: Option<String>,
 }
 
 impl Argument {
+    /// Tells if argument is passed to Blueprint by reference with `UPARAM(ref)`.
+    pub fn is_ref(&self) -> bool {
+        self.specifiers
+            .as_ref()
+            .map(|specifiers| {
+                specifiers.attributes.iter().any(|attribute| {
+                    matches!(attribute, Attribute::Single(name) if name.eq_ignore_ascii_case("ref"))
+                })
+            })
+            .unwrap_or_default()
+    }
+
+    /// Tells if argument is an output, that is non-const reference not marked with
+    /// `UPARAM(ref)`.
+    pub fn is_output(&self) -> bool {
+        let value_type = self.value_type.trim();
+        match value_type.strip_suffix('&') {
+            Some(value_type) if !value_type.ends_with('&') => {
+                !self.is_ref()
+                    && !value_type.starts_with("const ")
+                    && !value_type.trim_end().ends_with(" const")
+            }
+            _ => false,
+        }
+    }
+
+    /// Display name set with `DisplayName` specifier or meta specifier.
+    pub fn display_name(&self) -> Option<&str> {
+        let specifiers = self.specifiers.as_ref()?;
+        specifiers
+            .attributes
+            .iter()
+            .chain(specifiers.meta.iter())
+            .find_map(|attribute| match attribute {
+                Attribute::Pair { key, value } if key.eq_ignore_ascii_case("DisplayName") => {
+                    Some(value.as_str())
+                }
+                _ => None,
+            })
+    }
+
     pub fn signature(&self) -> String {
         let mut result = self.value_type.to_owned();
         if let Some(name) = &self.name {
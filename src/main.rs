@@ -7,7 +7,9 @@ mod config;
 mod document;
 
 use crate::{
-    ast::unreal_cpp_header::parse_unreal_cpp_header,
+    ast::unreal_cpp_header::{
+        parse_unreal_cpp_header, parse_unreal_cpp_source, resolve_macro_injects, Diagnostic,
+    },
     backends::{json::bake_json, mdbook::bake_mdbook},
     config::*,
     document::Document,
@@ -55,11 +57,29 @@ fn main() {
     let (mut config, dir) = load_config(&input, output);
 
     let mut document = Document::default();
+    let mut diagnostics = vec![];
     for path in &config.input_dirs {
-        document_path(path, path, &mut document, &config.settings);
+        document_path(
+            path,
+            path,
+            &config.input_extensions,
+            &mut document,
+            &config.settings,
+            &mut diagnostics,
+        );
     }
-    document.resolve_injects();
+    document.resolve_nested_types();
+    document.resolve_interfaces(&config.settings);
+    for (file, diagnostic) in resolve_macro_injects(&mut document, &config.settings) {
+        diagnostics.push((source_path(&config.input_dirs, &file), diagnostic));
+    }
+    let warnings = document
+        .resolve_injects()
+        .into_iter()
+        .map(|(file, diagnostic)| (source_path(&config.input_dirs, &file), diagnostic))
+        .collect::<Vec<_>>();
     document.resolve_self_names_in_docs();
+    document.resolve_doc_tags();
     document.sort_items_by_name();
 
     match config.backend {
@@ -73,6 +93,38 @@ fn main() {
             bake_mdbook(&document, &config, &dir)
         }
     }
+
+    if !diagnostics.is_empty() {
+        println!("Skipped {} unsupported declarations:", diagnostics.len());
+        print_diagnostics(&diagnostics);
+    }
+    if !warnings.is_empty() {
+        println!("Found {} warnings:", warnings.len());
+        print_diagnostics(&warnings);
+    }
+}
+
+fn print_diagnostics(diagnostics: &[(PathBuf, Diagnostic)]) {
+    for (path, diagnostic) in diagnostics {
+        println!(
+            "- {}:{}:{}: {}",
+            path.display(),
+            diagnostic.line,
+            diagnostic.column,
+            diagnostic.message
+        );
+    }
+}
+
+/// Turns file path relative to input directory back into the canonicalized path parse
+/// diagnostics are reported with.
+fn source_path(input_dirs: &[PathBuf], file: &str) -> PathBuf {
+    input_dirs
+        .iter()
+        .map(|dir| dir.join(file))
+        .find(|path| path.is_file())
+        .map(|path| path.canonicalize().unwrap_or(path))
+        .unwrap_or_else(|| PathBuf::from(file))
 }
 
 fn load_config(input: &Path, output: Option<&Path>) -> (Config, PathBuf) {
@@ -107,15 +159,39 @@ fn load_config(input: &Path, output: Option<&Path>) -> (Config, PathBuf) {
     (config, dir)
 }
 
-fn document_path(path: &Path, root: &Path, document: &mut Document, settings: &Settings) {
+fn document_path(
+    path: &Path,
+    root: &Path,
+    extensions: &InputExtensions,
+    document: &mut Document,
+    settings: &Settings,
+    diagnostics: &mut Vec<(PathBuf, Diagnostic)>,
+) {
     if path.is_file() {
         if let Some(ext) = path.extension() {
-            if ext == "h" {
+            let ext = ext.to_string_lossy();
+            let is_kind = |list: &[String]| {
+                list.iter()
+                    .any(|item| item.trim_start_matches('.').eq_ignore_ascii_case(&ext))
+            };
+            if is_kind(&extensions.headers) || is_kind(&extensions.sources) {
+                let relative = path
+                    .strip_prefix(root)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .replace('\\', "/");
                 let path = path.canonicalize().unwrap_or_else(|_| path.to_owned());
                 let content =
                     read_file(&path).unwrap_or_else(|_| panic!("Could not read file: {:?}", &path));
-                document_header(&path, &content, document, settings);
-            } else if ext == "md" {
+                let result = if is_kind(&extensions.headers) {
+                    parse_unreal_cpp_header(&relative, &content, document, settings)
+                } else {
+                    parse_unreal_cpp_source(&relative, &content, document, settings)
+                };
+                for diagnostic in result {
+                    diagnostics.push((path.to_owned(), diagnostic));
+                }
+            } else if is_kind(&extensions.book) {
                 let content =
                     read_file(path).unwrap_or_else(|_| panic!("Could not read file: {:?}", path));
                 let root = root.to_string_lossy().into_owned();
@@ -149,20 +225,11 @@ fn document_path(path: &Path, root: &Path, document: &mut Document, settings: &S
             .unwrap_or_else(|_| panic!("Could not read directory: {:?}", path))
         {
             let path = entry.expect("Could not read directory entry!").path();
-            document_path(&path, root, document, settings);
+            document_path(&path, root, extensions, document, settings, diagnostics);
         }
     }
 }
 
-fn document_header(path: &Path, content: &str, document: &mut Document, settings: &Settings) {
-    parse_unreal_cpp_header(content, document, settings).unwrap_or_else(|error| {
-        panic!(
-            "Could not parse Unreal C++ header file content!\nFile: {:?}\nError:\n{}",
-            path, error
-        )
-    });
-}
-
 fn ensure_dir(path: &Path) {
     if path.is_dir() {
         let _ = create_dir_all(path);